@@ -0,0 +1,203 @@
+//! List One comparison
+
+use iso4217_parser::{CurrencyDoc, CurrencyEntry, CurrencyName};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// A single difference between two documents.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Change {
+    /// A currency present only in the newer document.
+    Added(String),
+    /// A currency present only in the older document.
+    Removed(String),
+    /// A field of a currency present in both documents changed.
+    Changed {
+        /// The currency code.
+        currency: String,
+        /// The name of the field that changed.
+        field: &'static str,
+        /// The older value.
+        old: String,
+        /// The newer value.
+        new: String,
+    },
+    /// A country started using a currency.
+    CountryAdded {
+        /// The currency code.
+        currency: String,
+        /// The country name.
+        country: String,
+    },
+    /// A country stopped using a currency.
+    CountryRemoved {
+        /// The currency code.
+        currency: String,
+        /// The country name.
+        country: String,
+    },
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Added(currency) => write!(f, "+ {currency}"),
+            Self::Removed(currency) => write!(f, "- {currency}"),
+            Self::Changed {
+                currency,
+                field,
+                old,
+                new,
+            } => write!(f, "~ {currency} {field}: {old} -> {new}"),
+            Self::CountryAdded { currency, country } => write!(f, "+ {currency} in {country}"),
+            Self::CountryRemoved { currency, country } => write!(f, "- {currency} in {country}"),
+        }
+    }
+}
+
+/// The distinct currencies of a document, by code.
+fn currencies(doc: &CurrencyDoc) -> BTreeMap<&str, &CurrencyEntry> {
+    doc.table()
+        .index()
+        .currencies()
+        .iter()
+        .filter_map(|&entry| Some((entry.currency()?, entry)))
+        .collect()
+}
+
+/// The (currency, country) pairs of a document.
+fn countries(doc: &CurrencyDoc) -> BTreeSet<(&str, &str)> {
+    doc.table()
+        .entries()
+        .iter()
+        .filter_map(|entry| Some((entry.currency()?, entry.country())))
+        .collect()
+}
+
+/// The fields compared between two entries for the same currency.
+fn fields(entry: &CurrencyEntry) -> [(&'static str, String); 4] {
+    [
+        (
+            "number",
+            entry
+                .number()
+                .map(|number| format!("{number:03}"))
+                .unwrap_or_default(),
+        ),
+        (
+            "name",
+            entry
+                .name()
+                .map(|name| name.name().to_owned())
+                .unwrap_or_default(),
+        ),
+        (
+            "minor unit",
+            entry
+                .minor_unit()
+                .map_or_else(|| "N.A.".to_owned(), |unit| unit.to_string()),
+        ),
+        (
+            "fund",
+            entry.name().is_some_and(CurrencyName::is_fund).to_string(),
+        ),
+    ]
+}
+
+/// Compare two documents, returning the changes from `old` to `new`, ordered by kind and code.
+pub(crate) fn diff(old: &CurrencyDoc, new: &CurrencyDoc) -> Vec<Change> {
+    let mut retval = Vec::new();
+
+    let old_currencies = currencies(old);
+    let new_currencies = currencies(new);
+
+    for (&currency, &old_entry) in &old_currencies {
+        let Some(&new_entry) = new_currencies.get(currency) else {
+            retval.push(Change::Removed(currency.to_owned()));
+            continue;
+        };
+
+        for ((field, old), (_, new)) in fields(old_entry).into_iter().zip(fields(new_entry)) {
+            if old != new {
+                retval.push(Change::Changed {
+                    currency: currency.to_owned(),
+                    field,
+                    old,
+                    new,
+                });
+            }
+        }
+    }
+
+    for &currency in new_currencies.keys() {
+        if !old_currencies.contains_key(currency) {
+            retval.push(Change::Added(currency.to_owned()));
+        }
+    }
+
+    let old_countries = countries(old);
+    let new_countries = countries(new);
+
+    for &(currency, country) in old_countries.difference(&new_countries) {
+        retval.push(Change::CountryRemoved {
+            currency: currency.to_owned(),
+            country: country.to_owned(),
+        });
+    }
+
+    for &(currency, country) in new_countries.difference(&old_countries) {
+        retval.push(Change::CountryAdded {
+            currency: currency.to_owned(),
+            country: country.to_owned(),
+        });
+    }
+
+    retval.sort();
+    retval
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quick_xml::de;
+
+    const OLD: &str = r#"<ISO_4217 Pblshd="2025-01-01"><CcyTbl>
+        <CcyNtry><CtryNm>CROATIA</CtryNm><CcyNm>Kuna</CcyNm><Ccy>HRK</Ccy><CcyNbr>191</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+        <CcyNtry><CtryNm>SIERRA LEONE</CtryNm><CcyNm>Leone</CcyNm><Ccy>SLE</Ccy><CcyNbr>925</CcyNbr><CcyMnrUnts>0</CcyMnrUnts></CcyNtry>
+    </CcyTbl></ISO_4217>"#;
+
+    const NEW: &str = r#"<ISO_4217 Pblshd="2026-01-01"><CcyTbl>
+        <CcyNtry><CtryNm>CROATIA</CtryNm><CcyNm>Euro</CcyNm><Ccy>EUR</Ccy><CcyNbr>978</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+        <CcyNtry><CtryNm>SIERRA LEONE</CtryNm><CcyNm>Leone</CcyNm><Ccy>SLE</Ccy><CcyNbr>925</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+    </CcyTbl></ISO_4217>"#;
+
+    #[test]
+    fn changes() {
+        let old = de::from_str::<CurrencyDoc>(OLD).expect("old");
+        let new = de::from_str::<CurrencyDoc>(NEW).expect("new");
+
+        let actual = diff(&old, &new)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "+ EUR",
+                "- HRK",
+                "~ SLE minor unit: 0 -> 2",
+                "+ EUR in CROATIA",
+                "- HRK in CROATIA",
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        let doc = de::from_str::<CurrencyDoc>(iso4217_static::SOURCE_XML).expect("XML");
+        assert!(diff(&doc, &doc).is_empty());
+    }
+}
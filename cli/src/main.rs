@@ -0,0 +1,298 @@
+//! Command-line Tool for Querying and Validating ISO 4217 Data
+
+#![doc = include_str!("../README.md")]
+
+mod diff;
+mod validate;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use iso3166_static::{Alpha2, Alpha3, Numeric};
+use iso4217_parser::{CurrencyDoc, CurrencyEntry, CurrencyName};
+use iso4217_static::Currency;
+use quick_xml::de;
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufReader, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// The result type used by subcommands.
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Query and validate ISO 4217 currency data.
+#[derive(Debug, Parser)]
+#[command(name = "iso4217", version, about)]
+struct Cli {
+    /// The subcommand to run.
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// The available subcommands.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Look up a currency by its alphabetic (`USD`) or numeric (`840`) code.
+    Lookup {
+        /// The alphabetic or numeric currency code.
+        code: String,
+
+        /// The data source to use.
+        #[command(flatten)]
+        source: Source,
+    },
+
+    /// List the distinct currencies, in numeric order.
+    List {
+        /// Only list funds.
+        #[arg(long)]
+        funds: bool,
+
+        /// Only list currencies used by the country with the given ISO 3166 alpha-2 (`CH`),
+        /// alpha-3 (`CHE`) or numeric (`756`) code, according to the embedded data.
+        #[arg(long)]
+        country: Option<String>,
+
+        /// The data source to use.
+        #[command(flatten)]
+        source: Source,
+    },
+
+    /// Check a List One XML file for malformed or inconsistent entries.
+    Validate {
+        /// The XML file to validate.
+        file: PathBuf,
+    },
+
+    /// Show the currency changes between two List One XML files.
+    Diff {
+        /// The older XML file.
+        old: PathBuf,
+
+        /// The newer XML file.
+        new: PathBuf,
+    },
+
+    /// Export every entry to standard output.
+    Export {
+        /// The output format.
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+
+        /// The data source to use.
+        #[command(flatten)]
+        source: Source,
+    },
+}
+
+/// Where the currency data should be read from.
+#[derive(Args, Debug)]
+struct Source {
+    /// Read a List One XML file instead of the data embedded in `iso4217-static`.
+    #[arg(long)]
+    xml: Option<PathBuf>,
+}
+
+impl Source {
+    /// Load the currency document from this source.
+    fn load(&self) -> Result<CurrencyDoc> {
+        match &self.xml {
+            Some(path) => load(path),
+            None => Ok(de::from_str(iso4217_static::SOURCE_XML)?),
+        }
+    }
+}
+
+/// Export formats.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+enum Format {
+    /// Comma-separated values, with a header row.
+    Csv,
+    /// JSON lines, one record per line.
+    Json,
+}
+
+/// Load a currency document from the given XML file.
+fn load(path: &Path) -> Result<CurrencyDoc> {
+    let file = File::open(path)
+        .map_err(|error| format!("Could not open `{}`: {error}", path.display()))?;
+
+    de::from_reader(BufReader::new(file))
+        .map_err(|error| format!("Could not parse `{}`: {error}", path.display()).into())
+}
+
+/// Write a one-line summary of the given entry.
+fn write_summary<W: Write>(writer: &mut W, entry: &CurrencyEntry) -> io::Result<()> {
+    let currency = entry.currency().unwrap_or_default();
+    let number = entry
+        .number()
+        .map(|number| format!("{number:03}"))
+        .unwrap_or_default();
+    let minor_unit = entry
+        .minor_unit()
+        .map_or_else(|| "N.A.".to_owned(), |unit| unit.to_string());
+    let name = entry.name().map(CurrencyName::name).unwrap_or_default();
+    let fund = if entry.name().is_some_and(CurrencyName::is_fund) {
+        " (fund)"
+    } else {
+        ""
+    };
+
+    writeln!(
+        writer,
+        "{currency:3} {number:3} {minor_unit:>4} {name}{fund}"
+    )
+}
+
+/// Look up a currency by code.
+fn lookup(code: &str, source: &Source) -> Result<bool> {
+    let doc = source.load()?;
+    let index = doc.table().index();
+    let code = code.trim();
+
+    let entries = match code.parse::<u16>() {
+        Ok(number) => index.by_number(number),
+        Err(_) => index.by_currency(&code.to_ascii_uppercase()),
+    };
+
+    let Some(first) = entries.first() else {
+        eprintln!("No currency found for `{code}`");
+        return Ok(false);
+    };
+
+    let mut stdout = io::stdout().lock();
+    write_summary(&mut stdout, first)?;
+    for entry in entries {
+        writeln!(stdout, "    {}", entry.country())?;
+    }
+
+    Ok(true)
+}
+
+/// The country with the given alpha-2, alpha-3 or numeric code.
+fn country(code: &str) -> Option<Alpha2> {
+    let alpha2 = match code.parse::<u16>() {
+        Ok(number) => Numeric::from_u16(number).and_then(Alpha2::from_numeric),
+        Err(_) if code.len() == 3 => {
+            Alpha3::from_str_slice(&code.to_ascii_uppercase()).and_then(Alpha2::from_alpha3)
+        }
+        Err(_) => Alpha2::from_str_slice(&code.to_ascii_uppercase()),
+    };
+
+    alpha2.ok()
+}
+
+/// List the distinct currencies, optionally filtered.
+fn list(funds: bool, country: Option<&str>, source: &Source) -> Result<bool> {
+    let country = match country.map(str::trim) {
+        Some(code) => {
+            let Some(alpha2) = self::country(code) else {
+                eprintln!("No country found for `{code}`");
+                return Ok(false);
+            };
+            Some(alpha2)
+        }
+        None => None,
+    };
+
+    let doc = source.load()?;
+    let index = doc.table().index();
+
+    let mut stdout = io::stdout().lock();
+    for &entry in index.currencies() {
+        if funds && !entry.name().is_some_and(CurrencyName::is_fund) {
+            continue;
+        }
+
+        if let Some(country) = country
+            && !used_by(entry, country)
+        {
+            continue;
+        }
+
+        write_summary(&mut stdout, entry)?;
+    }
+
+    Ok(true)
+}
+
+/// Whether the given currency is used by the given country, according to the data embedded in
+/// `iso4217-static`.
+fn used_by(entry: &CurrencyEntry, country: Alpha2) -> bool {
+    entry
+        .currency()
+        .and_then(|code| Currency::from_str_slice(code).ok())
+        .is_some_and(|currency| currency.alpha2_countries().contains(&country))
+}
+
+/// Validate an XML file, printing every problem found.
+fn validate(file: &Path) -> Result<bool> {
+    let doc = load(file)?;
+    let problems = validate::validate(&doc);
+
+    for problem in &problems {
+        eprintln!("{}: {problem}", file.display());
+    }
+
+    if problems.is_empty() {
+        println!(
+            "{}: {} entries, no problems found",
+            file.display(),
+            doc.table().entries().len()
+        );
+    }
+
+    Ok(problems.is_empty())
+}
+
+/// Print the currency changes between two XML files.
+fn diff(old: &Path, new: &Path) -> Result<bool> {
+    let old = load(old)?;
+    let new = load(new)?;
+
+    let mut stdout = io::stdout().lock();
+    for change in diff::diff(&old, &new) {
+        writeln!(stdout, "{change}")?;
+    }
+
+    Ok(true)
+}
+
+/// Export every entry in the given format.
+fn export(format: Format, source: &Source) -> Result<bool> {
+    let doc = source.load()?;
+    let stdout = io::stdout().lock();
+
+    match format {
+        Format::Csv => doc.table().write_csv(stdout)?,
+        Format::Json => doc.table().write_json_lines(stdout)?,
+    }
+
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match &cli.command {
+        Command::Lookup { code, source } => lookup(code, source),
+        Command::List {
+            funds,
+            country,
+            source,
+        } => list(*funds, country.as_deref(), source),
+        Command::Validate { file } => validate(file),
+        Command::Diff { old, new } => diff(old, new),
+        Command::Export { format, source } => export(*format, source),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
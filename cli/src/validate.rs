@@ -0,0 +1,118 @@
+//! List One validation
+
+use iso4217_parser::{CurrencyDoc, CurrencyEntry};
+use std::collections::BTreeMap;
+
+/// Check the given document for malformed or inconsistent entries.
+///
+/// Returns a description of each problem found, in document order.
+pub(crate) fn validate(doc: &CurrencyDoc) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if let Err(error) = doc.published() {
+        problems.push(format!("Invalid publication date: {error}"));
+    }
+
+    // The first entry seen for each code and number.
+    let mut by_currency = BTreeMap::<&str, &CurrencyEntry>::new();
+    let mut by_number = BTreeMap::<u16, &CurrencyEntry>::new();
+
+    for entry in doc.table().entries() {
+        let country = entry.country();
+
+        if country.is_empty() {
+            problems.push("Entry without a country name".to_owned());
+        }
+
+        let Some(currency) = entry.currency() else {
+            if entry.number().is_some() {
+                problems.push(format!("{country}: numeric code without a currency code"));
+            }
+            continue;
+        };
+
+        if currency.len() != 3 || !currency.bytes().all(|byte| byte.is_ascii_uppercase()) {
+            problems.push(format!(
+                "{country}: `{currency}` is not a 3-letter uppercase code"
+            ));
+        }
+
+        let Some(number) = entry.number() else {
+            problems.push(format!("{country}: {currency} has no numeric code"));
+            continue;
+        };
+
+        if number > 999 {
+            problems.push(format!(
+                "{country}: {currency} has a numeric code with more than 3 digits ({number})"
+            ));
+        }
+
+        if entry.name().is_none_or(|name| name.name().is_empty()) {
+            problems.push(format!("{country}: {currency} has no name"));
+        }
+
+        let first = *by_currency.entry(currency).or_insert(entry);
+        if first.number() != entry.number() {
+            problems.push(format!(
+                "{country}: {currency} uses {number:03}, but {} uses {:03}",
+                first.country(),
+                first.number().unwrap_or_default()
+            ));
+        }
+
+        if first.name() != entry.name() {
+            problems.push(format!(
+                "{country}: {currency} has a different name or fund status than in {}",
+                first.country()
+            ));
+        }
+
+        if first.minor_unit() != entry.minor_unit() {
+            problems.push(format!(
+                "{country}: {currency} has a different minor unit than in {}",
+                first.country()
+            ));
+        }
+
+        let first = *by_number.entry(number).or_insert(entry);
+        if first.currency() != entry.currency() {
+            problems.push(format!(
+                "{country}: {number:03} is used by both {currency} and {}",
+                first.currency().unwrap_or_default()
+            ));
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quick_xml::de;
+
+    #[test]
+    fn embedded() {
+        let doc = de::from_str::<CurrencyDoc>(iso4217_static::SOURCE_XML).expect("XML");
+        assert!(validate(&doc).is_empty());
+    }
+
+    #[test]
+    fn inconsistent() {
+        const XML: &str = r#"<ISO_4217 Pblshd="2026-13-01"><CcyTbl>
+            <CcyNtry><CtryNm>A</CtryNm><CcyNm>Dollar</CcyNm><Ccy>USD</Ccy><CcyNbr>840</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+            <CcyNtry><CtryNm>B</CtryNm><CcyNm>Dollar</CcyNm><Ccy>USD</Ccy><CcyNbr>841</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+            <CcyNtry><CtryNm>C</CtryNm><CcyNm>Euro</CcyNm><Ccy>eur</Ccy><CcyNbr>840</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+        </CcyTbl></ISO_4217>"#;
+
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+        let problems = validate(&doc);
+
+        assert_eq!(4, problems.len(), "{problems:#?}");
+        assert!(problems[0].starts_with("Invalid publication date"));
+        assert_eq!("B: USD uses 841, but A uses 840", problems[1]);
+        assert_eq!("C: `eur` is not a 3-letter uppercase code", problems[2]);
+        assert_eq!("C: 840 is used by both eur and USD", problems[3]);
+    }
+}
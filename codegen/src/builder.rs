@@ -0,0 +1,740 @@
+//! Code generator builder
+
+use crate::{
+    Error, Lookup, emit, filter::Filter, load, lookup, naming::Strategy, recordset::EntrySet,
+};
+use iso4217_parser::CurrencyEntry;
+use proc_macro2::TokenStream;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use syn::{Ident, Visibility};
+
+/// The format of the data file.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// ISO 4217 List One XML.
+    #[default]
+    Xml,
+    /// JSON lines of normalized currency records.
+    Json,
+    /// CSV of normalized currency records.
+    Csv,
+}
+
+impl Format {
+    /// The name of the option used to select this format.
+    #[must_use]
+    pub const fn param(self) -> &'static str {
+        match self {
+            Self::Xml => "xml",
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// The groups of methods which may be generated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Methods {
+    /// `from_u16()`
+    pub numeric: bool,
+    /// `from_str_slice()`, `from_str_ignore_case()` and `as_str()`, plus `from_str_lenient()` with
+    /// `numeric`
+    pub string: bool,
+    /// `name()`
+    pub name: bool,
+    /// `is_fund()`
+    pub fund: bool,
+    /// `minor_unit()`
+    pub minor_unit: bool,
+    /// `from_numeric_country()`, `from_alpha2_country()`, `from_alpha3_country()` and
+    /// `alpha2_countries()`
+    pub country: bool,
+    /// `ALL`, `index()` and `from_index()`
+    pub index: bool,
+    /// `info()`, which requires `index`
+    pub info: bool,
+}
+
+impl Methods {
+    /// No method groups.
+    pub const NONE: Self = Self {
+        numeric: false,
+        string: false,
+        name: false,
+        fund: false,
+        minor_unit: false,
+        country: false,
+        index: false,
+        info: false,
+    };
+
+    /// Every method group.
+    pub const ALL: Self = Self {
+        numeric: true,
+        string: true,
+        name: true,
+        fund: true,
+        minor_unit: true,
+        country: true,
+        index: true,
+        info: true,
+    };
+}
+
+impl Default for Methods {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl FromStr for Methods {
+    type Err = Error;
+
+    /// Parse a comma-separated list of method groups, e.g. `"numeric, string"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut methods = Self::NONE;
+
+        for group in s.split(',').map(str::trim) {
+            match group {
+                "all" => methods = Self::ALL,
+                "numeric" => methods.numeric = true,
+                "string" => methods.string = true,
+                "name" => methods.name = true,
+                "fund" => methods.fund = true,
+                "minor_unit" => methods.minor_unit = true,
+                "country" => methods.country = true,
+                "index" => methods.index = true,
+                "info" => methods.info = true,
+                "" => {}
+                other => {
+                    let message = format!(
+                        "`{other}` is not a method group, which are \"numeric\", \"string\", \"name\", \"fund\", \"minor_unit\", \"country\", \"index\", \"info\" or \"all\""
+                    );
+                    return Err(Error::new(Some("methods"), message));
+                }
+            }
+        }
+
+        Ok(methods)
+    }
+}
+
+/// A generator for ISO 4217 currency enums.
+///
+/// Each method corresponds to a parameter of the `generate!` macro in `iso4217-macros`, but paths
+/// are used as given rather than being relative to the calling crate's `src` directory.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    /// The path to the data file, and its format.
+    data: Option<(PathBuf, Format)>,
+    /// The path to an overlay file.
+    overlay: Option<PathBuf>,
+    /// The path to a file of per-code variant identifiers.
+    idents: Option<PathBuf>,
+    /// The variant naming strategy.
+    naming: Strategy,
+    /// The name of the generated enum.
+    type_name: String,
+    /// The name of the generated error enum.
+    error_name: String,
+    /// The visibility of the generated items.
+    vis: String,
+    /// The method groups to generate.
+    methods: Methods,
+    /// How the methods look up values.
+    lookup: Lookup,
+    /// The entries to generate.
+    filter: Filter,
+    /// The path of a complete enum to generate conversions to and from.
+    convert: Option<String>,
+    /// The feature name to use for zerocopy.
+    zerocopy: Option<String>,
+    /// Whether to generate dataset metadata constants.
+    metadata: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            data: None,
+            overlay: None,
+            idents: None,
+            naming: Strategy::default(),
+            type_name: "Currency".to_owned(),
+            error_name: "Error".to_owned(),
+            vis: "pub".to_owned(),
+            methods: Methods::default(),
+            lookup: Lookup::default(),
+            filter: Filter::default(),
+            convert: None,
+            zerocopy: None,
+            metadata: false,
+        }
+    }
+}
+
+/// Parse an identifier given to the named option.
+fn parse_ident(param: &'static str, value: &str) -> Result<Ident, Error> {
+    syn::parse_str::<Ident>(value).map_err(|_error| {
+        let message = format!("`{param}` must be an identifier, not `{value}`");
+        Error::new(Some(param), message)
+    })
+}
+
+impl Builder {
+    /// Create a new builder, which must be given a data file before generating code.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the given data file, in the given format.
+    #[must_use]
+    pub fn data(mut self, path: impl Into<PathBuf>, format: Format) -> Self {
+        self.data = Some((path.into(), format));
+        self
+    }
+
+    /// Read the given ISO 4217 List One XML file.
+    #[must_use]
+    pub fn xml(self, path: impl Into<PathBuf>) -> Self {
+        self.data(path, Format::Xml)
+    }
+
+    /// Read the given JSON lines file of normalized currency records.
+    #[must_use]
+    pub fn json(self, path: impl Into<PathBuf>) -> Self {
+        self.data(path, Format::Json)
+    }
+
+    /// Read the given CSV file of normalized currency records.
+    #[must_use]
+    pub fn csv(self, path: impl Into<PathBuf>) -> Self {
+        self.data(path, Format::Csv)
+    }
+
+    /// Apply the given JSON lines overlay file to the data.
+    #[must_use]
+    pub fn overlay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.overlay = Some(path.into());
+        self
+    }
+
+    /// Name enum variants with the given strategy.
+    #[must_use]
+    pub fn naming(mut self, strategy: Strategy) -> Self {
+        self.naming = strategy;
+        self
+    }
+
+    /// Read per-code variant names from the given JSON file.
+    #[must_use]
+    pub fn idents(mut self, path: impl Into<PathBuf>) -> Self {
+        self.idents = Some(path.into());
+        self
+    }
+
+    /// Set the name of the currency enum, `Currency` by default.
+    #[must_use]
+    pub fn type_name(mut self, name: impl Into<String>) -> Self {
+        self.type_name = name.into();
+        self
+    }
+
+    /// Set the name of the error enum, `Error` by default.
+    #[must_use]
+    pub fn error_name(mut self, name: impl Into<String>) -> Self {
+        self.error_name = name.into();
+        self
+    }
+
+    /// Set the visibility of the generated items, `pub` by default.
+    #[must_use]
+    pub fn vis(mut self, vis: impl Into<String>) -> Self {
+        self.vis = vis.into();
+        self
+    }
+
+    /// Set the method groups to generate, all of them by default.
+    #[must_use]
+    pub fn methods(mut self, methods: Methods) -> Self {
+        self.methods = methods;
+        self
+    }
+
+    /// Set how the methods look up values, with a `match` over every variant by default.
+    ///
+    /// [`Lookup::Table`] generates smaller code, at the cost of slower lookups.
+    #[must_use]
+    pub fn lookup(mut self, lookup: Lookup) -> Self {
+        self.lookup = lookup;
+        self
+    }
+
+    /// Set whether fund codes are generated, which they are by default.
+    #[must_use]
+    pub fn funds(mut self, funds: bool) -> Self {
+        self.filter.funds = funds;
+        self
+    }
+
+    /// Set whether codes starting with `X` are generated, which they are by default.
+    #[must_use]
+    pub fn x_codes(mut self, x_codes: bool) -> Self {
+        self.filter.x_codes = x_codes;
+        self
+    }
+
+    /// Only generate the given codes.
+    #[must_use]
+    pub fn include<I: IntoIterator<Item = S>, S: Into<String>>(mut self, codes: I) -> Self {
+        self.filter.include = Some(codes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Don't generate the given codes.
+    #[must_use]
+    pub fn exclude<I: IntoIterator<Item = S>, S: Into<String>>(mut self, codes: I) -> Self {
+        self.filter.exclude = Some(codes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only generate the codes used by the given countries, named as in the `iso3166-static` enums.
+    #[must_use]
+    pub fn countries<I: IntoIterator<Item = S>, S: Into<String>>(mut self, countries: I) -> Self {
+        self.filter.countries = Some(countries.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Generate conversions to and from the complete enum at the given path.
+    #[must_use]
+    pub fn convert(mut self, path: impl Into<String>) -> Self {
+        self.convert = Some(path.into());
+        self
+    }
+
+    /// Derive `zerocopy` traits when the given feature is enabled.
+    #[must_use]
+    pub fn zerocopy(mut self, feature: impl Into<String>) -> Self {
+        self.zerocopy = Some(feature.into());
+        self
+    }
+
+    /// Generate constants describing the data, which requires an XML data file and `chrono`.
+    ///
+    /// These are `PUBLISHED`, the publication date, `SOURCE_SHA256`, the digest of the data file,
+    /// and `GENERATOR_VERSION`, the version of this crate.
+    #[must_use]
+    pub fn metadata(mut self, metadata: bool) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The files which will be read when generating code.
+    pub fn inputs(&self) -> impl Iterator<Item = &Path> {
+        self.data
+            .iter()
+            .map(|(path, _format)| path)
+            .chain(&self.overlay)
+            .chain(&self.idents)
+            .map(PathBuf::as_path)
+    }
+
+    /// Generate the currency enum, error enum, and their implementations.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when no data file was given, one of the files cannot be read, or one of the
+    ///   options is invalid. [`Error::param()`] names the offending option.
+    pub fn generate(&self) -> Result<TokenStream, Error> {
+        let Some((path, format)) = &self.data else {
+            return Err(Error::new(
+                None,
+                "A data file must be given with one of `xml`, `json` or `csv`.",
+            ));
+        };
+
+        if self.methods.info && !self.methods.index {
+            return Err(Error::new(
+                Some("methods"),
+                "The \"info\" method group requires the \"index\" method group",
+            ));
+        }
+
+        let type_name = parse_ident("type_name", &self.type_name)?;
+
+        let mut config = emit::Config {
+            info_name: quote::format_ident!("{type_name}Info"),
+            kind_name: quote::format_ident!("{type_name}Kind"),
+            type_name,
+            error_name: parse_ident("error_name", &self.error_name)?,
+            vis: syn::parse_str::<Visibility>(&self.vis).map_err(|_error| {
+                let message = format!(
+                    "`vis` must be a visibility, such as \"pub\" or \"pub(crate)\", not `{}`",
+                    self.vis
+                );
+                Error::new(Some("vis"), message)
+            })?,
+            methods: self.methods,
+            lookup: self.lookup,
+            zerocopy: self.zerocopy.clone(),
+            convert: self
+                .convert
+                .as_deref()
+                .map(|convert| {
+                    syn::parse_str::<syn::Path>(convert).map_err(|_error| {
+                        let message =
+                            format!("`convert` must be a path to a type, not `{convert}`");
+                        Error::new(Some("convert"), message)
+                    })
+                })
+                .transpose()?,
+            metadata: None,
+        };
+
+        let (mut table, published) = load::table(path, *format)?;
+
+        if self.metadata {
+            let published = published.ok_or_else(|| {
+                Error::new(
+                    Some("metadata"),
+                    "`metadata` requires an `xml` data file with a publication date",
+                )
+            })?;
+
+            config.metadata = Some(emit::Metadata {
+                published,
+                sha256: load::sha256(path, *format)?,
+            });
+        }
+
+        if let Some(overlay) = &self.overlay {
+            load::overlay(&mut table, overlay)?;
+        }
+
+        let naming = load::naming(self.naming, self.idents.as_deref(), &table)?;
+
+        let mut entries = table.entries().to_vec();
+        entries.sort_by_cached_key(CurrencyEntry::number);
+        self.filter.apply(&mut entries)?;
+
+        let entryset = EntrySet::from_entries(&entries, &naming, format.param())?;
+
+        if self.lookup == Lookup::Table {
+            lookup::validate(&entryset)?;
+        }
+
+        if let Some(ident) = entryset
+            .ident()
+            .iter()
+            .find(|ident| config.constants().iter().any(|constant| *ident == constant))
+        {
+            let message = format!(
+                "The enum variant `{ident}` collides with the generated `{}::{ident}` constant, it should be renamed with `idents`",
+                config.type_name
+            );
+            return Err(Error::new(Some(format.param()), message));
+        }
+
+        Ok(emit::generate(&entryset, &naming, &config))
+    }
+
+    /// Generate formatted Rust source, suitable for use with `include!()`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when code cannot be generated, see [`Builder::generate()`].
+    pub fn to_source(&self) -> Result<String, Error> {
+        let tokens = self.generate()?;
+        let file = syn::parse2::<syn::File>(tokens).map_err(|error| {
+            let message = format!("Could not parse generated code: {error}");
+            Error::new(None, message)
+        })?;
+
+        Ok(format!(
+            "// @generated by iso4217-codegen {}, do not edit.\n\n{}",
+            env!("CARGO_PKG_VERSION"),
+            prettyplease::unparse(&file)
+        ))
+    }
+
+    /// Write formatted Rust source to the given path.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when code cannot be generated, see [`Builder::generate()`], or the file cannot
+    ///   be written.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let source = self.to_source()?;
+
+        fs::write(path, source).map_err(|error| {
+            let message = format!("Could not write `{}`: {error}", path.display());
+            Error::new(None, message)
+        })
+    }
+
+    /// Write formatted Rust source to the given file name in `OUT_DIR`, from a build script.
+    ///
+    /// This also prints `cargo:rerun-if-changed` for each of the [inputs](Builder::inputs()), and
+    /// returns the path written to, for use with `include!()`:
+    ///
+    /// ```rust,ignore
+    /// include!(concat!(env!("OUT_DIR"), "/currency.rs"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when not run from a build script, code cannot be generated, or the file cannot
+    ///   be written.
+    pub fn write_to_out_dir(&self, file_name: impl AsRef<Path>) -> Result<PathBuf, Error> {
+        let out_dir = env::var_os("OUT_DIR").ok_or_else(|| {
+            Error::new(
+                None,
+                "OUT_DIR variable not found, `write_to_out_dir()` must be called from a build script",
+            )
+        })?;
+
+        for input in self.inputs() {
+            println!("cargo:rerun-if-changed={}", input.display());
+        }
+
+        let path = Path::new(&out_dir).join(file_name);
+        self.write(&path)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CSV: &str = concat!(
+        "country,name,currency,number,minor_unit,is_fund\n",
+        "ALBANIA,Lek,ALL,008,2,false\n",
+        "LIECHTENSTEIN,Swiss Franc,CHF,756,2,false\n",
+        "SWITZERLAND,WIR Euro,CHE,947,2,true\n",
+        "SWITZERLAND,Swiss Franc,CHF,756,2,false\n",
+        "UNITED STATES OF AMERICA (THE),US Dollar,USD,840,2,false\n",
+        "ZZ08_Gold,Gold,XAU,959,,false\n",
+    );
+
+    /// A unique temporary directory holding the test data, which is removed when dropped.
+    struct Fixture {
+        /// The directory.
+        dir: PathBuf,
+    }
+
+    impl Fixture {
+        /// Create a new directory and write the test data into it.
+        fn new() -> Self {
+            static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+            let dir = env::temp_dir().join(format!(
+                "iso4217-codegen-{}-{}",
+                std::process::id(),
+                NEXT.fetch_add(1, Ordering::Relaxed)
+            ));
+            fs::create_dir_all(&dir).expect("create");
+            fs::write(dir.join("data.csv"), CSV).expect("write");
+
+            Self { dir }
+        }
+
+        /// The path of the test data.
+        fn csv(&self) -> PathBuf {
+            self.dir.join("data.csv")
+        }
+
+        /// Write the given idents to the directory.
+        fn idents(&self, json: &str) -> PathBuf {
+            let path = self.dir.join("idents.json");
+            fs::write(&path, json).expect("write");
+            path
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn to_source() {
+        let fixture = Fixture::new();
+        let source = Builder::new()
+            .csv(fixture.csv())
+            .naming(Strategy::Code)
+            .funds(false)
+            .exclude(["ALL", "XAU"])
+            .to_source()
+            .expect("source");
+
+        assert!(source.starts_with("// @generated by iso4217-codegen"));
+        assert!(source.contains("pub enum Currency {"));
+        assert!(source.contains("    CHF = 756u16,"));
+        assert!(source.contains("    USD = 840u16,"));
+        assert!(source.contains("pub struct CurrencyInfo {"));
+        assert!(source.contains("kind: CurrencyKind::LegalTender,"));
+        assert!(!source.contains("CHE"));
+        assert!(!source.contains("XAU"));
+    }
+
+    #[test]
+    fn lookup() {
+        let fixture = Fixture::new();
+        let source = Builder::new()
+            .csv(fixture.csv())
+            .lookup("table".parse().expect("lookup"))
+            .to_source()
+            .expect("source");
+
+        assert!(source.contains(
+            "static CURRENCY_NUMBERS: [u16; 5] = [8u16, 756u16, 840u16, 947u16, 959u16];"
+        ));
+        assert!(source.contains("const fn currency_position(number: u16) -> usize {"));
+        assert_eq!(
+            Some("lookup"),
+            "binary".parse::<Lookup>().expect_err("error").param()
+        );
+    }
+
+    #[test]
+    fn inputs() {
+        let builder = Builder::new()
+            .idents("idents.json")
+            .xml("list-one.xml")
+            .overlay("overlay.jsonl");
+
+        assert_eq!(
+            vec![
+                Path::new("list-one.xml"),
+                Path::new("overlay.jsonl"),
+                Path::new("idents.json")
+            ],
+            builder.inputs().collect::<Vec<_>>()
+        );
+    }
+
+    #[yare::parameterized(
+        no_data = { |_fixture| Builder::new(), None },
+        missing = { |_fixture| Builder::new().xml("/nonexistent/list-one.xml"), Some("xml") },
+        type_name = { |fixture| Builder::new().csv(fixture.csv()).type_name("1x"), Some("type_name") },
+        error_name = { |fixture| Builder::new().csv(fixture.csv()).error_name(""), Some("error_name") },
+        vis = { |fixture| Builder::new().csv(fixture.csv()).vis("public"), Some("vis") },
+        convert = { |fixture| Builder::new().csv(fixture.csv()).convert("1"), Some("convert") },
+        include = { |fixture| Builder::new().csv(fixture.csv()).include(["EUR"]), Some("include") },
+        countries = { |fixture| Builder::new().csv(fixture.csv()).countries(["France"]), Some("countries") },
+        idents = { |fixture| Builder::new().csv(fixture.csv()).idents("/nonexistent/idents.json"), Some("idents") },
+        constant = { |fixture| Builder::new().csv(fixture.csv()).naming(Strategy::Code), Some("csv") },
+        info = { |fixture| Builder::new().csv(fixture.csv()).methods(Methods { info: true, ..Methods::NONE }), Some("methods") },
+    )]
+    fn errors(builder: fn(&Fixture) -> Builder, param: Option<&str>) {
+        let fixture = Fixture::new();
+        let error = builder(&fixture).generate().expect_err("error");
+        assert_eq!(param, error.param(), "{error}");
+    }
+
+    #[yare::parameterized(
+        duplicate = {
+            r#"{"CHF": "UsDollar"}"#,
+            Some("csv"),
+            "The enum variant `UsDollar` generated for the UNITED STATES OF AMERICA (THE) entry for USD (840) collides with the one generated for CHF",
+        },
+        non_ascii = {
+            r#"{"USD": "Dólar"}"#,
+            Some("csv"),
+            "Invalid non-ASCII enum variant `Dólar` generated for the UNITED STATES OF AMERICA (THE) entry for USD (840)",
+        },
+        unknown = {
+            r#"{"EUR": "Euro"}"#,
+            Some("idents"),
+            "The `idents` file names EUR, which is not in the data",
+        },
+    )]
+    fn ident_errors(json: &str, param: Option<&str>, message: &str) {
+        let fixture = Fixture::new();
+        let error = Builder::new()
+            .csv(fixture.csv())
+            .idents(fixture.idents(json))
+            .generate()
+            .expect_err("error");
+
+        assert_eq!(param, error.param());
+        assert_eq!(message, error.message());
+    }
+
+    #[yare::parameterized(
+        name = { Strategy::Name, "UsDollar", "SwissFranc" },
+        code = { Strategy::Code, "USD", "CHF" },
+        code_pascal = { Strategy::CodePascal, "Usd", "Chf" },
+    )]
+    fn naming(strategy: Strategy, usd: &str, chf: &str) {
+        let fixture = Fixture::new();
+        let source = Builder::new()
+            .csv(fixture.csv())
+            .naming(strategy)
+            .exclude(["ALL"])
+            .to_source()
+            .expect("source");
+
+        assert!(source.contains(&format!("    {usd} = 840u16,")));
+        assert!(source.contains(&format!("    {chf} = 756u16,")));
+
+        let source = Builder::new()
+            .csv(fixture.csv())
+            .naming(strategy)
+            .exclude(["ALL"])
+            .idents(fixture.idents(r#"{"USD": "Greenback"}"#))
+            .to_source()
+            .expect("source");
+
+        assert!(source.contains("    Greenback = 840u16,"));
+        assert!(source.contains(&format!("    {chf} = 756u16,")));
+        assert!(!source.contains(&format!("    {usd} = 840u16,")));
+    }
+
+    #[test]
+    fn code_naming() {
+        let xml = Path::new(env!("CARGO_MANIFEST_DIR")).join("../static/src/list-one.xml");
+        let error = Builder::new()
+            .xml(&xml)
+            .naming(Strategy::Code)
+            .generate()
+            .expect_err("error");
+
+        assert_eq!(
+            "The enum variant `ALL` collides with the generated `Currency::ALL` constant, it should be renamed with `idents`",
+            error.message()
+        );
+
+        let fixture = Fixture::new();
+        let source = Builder::new()
+            .xml(&xml)
+            .naming(Strategy::Code)
+            .idents(fixture.idents(r#"{"ALL": "Lek"}"#))
+            .to_source()
+            .expect("source");
+
+        assert!(source.contains("    Lek = 8u16,"));
+        assert!(source.contains("    USD = 840u16,"));
+        assert!(source.contains("pub const ALL: [Self; Self::COUNT] = ["));
+    }
+
+    #[yare::parameterized(
+        all = { "all", Methods::ALL },
+        empty = { "", Methods::NONE },
+        some = { "numeric, string", Methods { numeric: true, string: true, ..Methods::NONE } },
+    )]
+    fn methods(value: &str, expected: Methods) {
+        assert_eq!(expected, value.parse::<Methods>().expect("methods"));
+    }
+}
@@ -0,0 +1,656 @@
+//! Token generation
+
+use crate::{
+    Lookup, Methods,
+    lookup::Tables,
+    naming::{Naming, Strategy},
+    recordset::EntrySet,
+};
+use chrono::{Datelike, NaiveDate};
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{Ident, Path, Visibility};
+
+/// Information about the data the enum was generated from.
+pub(crate) struct Metadata {
+    /// The publication date of the data.
+    pub published: NaiveDate,
+    /// The lowercase hex SHA-256 digest of the data file.
+    pub sha256: String,
+}
+
+/// The validated options which control the generated items.
+pub(crate) struct Config {
+    /// The name of the generated enum.
+    pub type_name: Ident,
+    /// The name of the generated error enum.
+    pub error_name: Ident,
+    /// The name of the generated info struct.
+    pub info_name: Ident,
+    /// The name of the generated kind enum.
+    pub kind_name: Ident,
+    /// The visibility of the generated items.
+    pub vis: Visibility,
+    /// The method groups to generate.
+    pub methods: Methods,
+    /// How the methods look up values.
+    pub lookup: Lookup,
+    /// The feature name to use for zerocopy.
+    pub zerocopy: Option<String>,
+    /// The path of a complete enum to generate conversions to and from.
+    pub convert: Option<Path>,
+    /// The dataset metadata constants to generate.
+    pub metadata: Option<Metadata>,
+}
+
+impl Config {
+    /// The associated constants which will be generated, which variants must not collide with.
+    pub(crate) fn constants(&self) -> Vec<&'static str> {
+        let mut retval = vec!["COUNT"];
+
+        if self.methods.index {
+            retval.push("ALL");
+        }
+
+        if self.metadata.is_some() {
+            retval.extend(["PUBLISHED", "SOURCE_SHA256", "GENERATOR_VERSION"]);
+        }
+
+        retval
+    }
+}
+
+/// Generate every item for the given entries.
+pub(crate) fn generate(entryset: &EntrySet, naming: &Naming, config: &Config) -> TokenStream {
+    let mut retval = build_error(config);
+    retval.extend(build_enum(entryset, naming, config));
+
+    if config.methods.info {
+        retval.extend(build_info_types(config));
+    }
+
+    if config.lookup == Lookup::Table {
+        retval.extend(Tables::new(config).build(entryset));
+    }
+
+    retval.extend(build_impl(entryset, config));
+
+    if let Some(convert) = &config.convert {
+        retval.extend(build_convert(entryset, config, convert));
+    }
+
+    retval
+}
+
+/// Generate the error enum.
+fn build_error(config: &Config) -> TokenStream {
+    let vis = &config.vis;
+    let error = &config.error_name;
+
+    quote::quote! {
+        /// Errors encountered when interacting ISO 4217 currency codes.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        #vis enum #error {
+            /// The code given is not a correct string or numeric code.
+            InvalidCode,
+            /// The code string given is the wrong length to be a currency code.
+            InvalidLength,
+            /// The code string given contains non-ASCII characters.
+            InvalidCharset,
+            /// The country in question does not have a universal currency.
+            NoUniversalCurrency,
+        }
+    }
+}
+
+/// Generate the currency code enum.
+fn build_enum(entryset: &EntrySet, naming: &Naming, config: &Config) -> TokenStream {
+    let doc = entryset.doc();
+    let id = entryset.ident();
+    let num = entryset.number();
+    let vis = &config.vis;
+    let ty = &config.type_name;
+
+    let zc_derive = if let Some(zc) = &config.zerocopy {
+        quote::quote! {
+            #[cfg_attr(feature = #zc, derive(::zerocopy::TryFromBytes, ::zerocopy::IntoBytes))]
+        }
+    } else {
+        quote::quote! {}
+    };
+
+    let allow_acronyms = if naming.strategy() == Strategy::Code {
+        quote::quote! {
+            #[allow(clippy::upper_case_acronyms)]
+        }
+    } else {
+        quote::quote! {}
+    };
+
+    quote::quote! {
+        /// ISO 4217 Currency Codes.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #allow_acronyms
+        #zc_derive
+        #[non_exhaustive]
+        #[repr(u16)]
+        #vis enum #ty {
+            #(
+                #[doc = #doc]
+                #id = #num,
+            )*
+        }
+    }
+}
+
+/// Generate the numeric lookup methods.
+fn build_numeric(entryset: &EntrySet, config: &Config) -> TokenStream {
+    let id = entryset.ident();
+    let number = entryset.number();
+    let vis = &config.vis;
+    let error = &config.error_name;
+
+    let body = match config.lookup {
+        Lookup::Match => quote::quote! {
+            match value {
+                #(
+                    #number => Ok(Self::#id),
+                )*
+
+                _ => Err(#error::InvalidCode),
+            }
+        },
+        Lookup::Table => Tables::new(config).numeric_lookup(),
+    };
+
+    quote::quote! {
+        /// Try to derive a currency from the given numeric code.
+        #vis const fn from_u16(value: u16) -> Result<Self, #error> {
+            #body
+        }
+    }
+}
+
+/// Generate the string lookup methods.
+fn build_string(entryset: &EntrySet, config: &Config) -> TokenStream {
+    let bytes = entryset.bytes();
+    let id = entryset.ident();
+    let vis = &config.vis;
+    let error = &config.error_name;
+    let tables = Tables::new(config);
+    let as_str = build_accessor(id, entryset.currency(), "code", tables.as_str(), config);
+
+    let lookup = match config.lookup {
+        Lookup::Match => quote::quote! {
+            match value.as_bytes() {
+                #(
+                    #bytes => Ok(Self::#id),
+                )*
+
+                _ => Err(#error::InvalidCode),
+            }
+        },
+        Lookup::Table => tables.code_lookup(),
+    };
+
+    quote::quote! {
+        /// Try to derive a currency from the given ASCII string slice.
+        #vis const fn from_str_slice(value: &str) -> Result<Self, #error> {
+            if value.len() != 3 {
+                return Err(#error::InvalidLength);
+            }
+
+            if !value.is_ascii() {
+                return Err(#error::InvalidCharset);
+            }
+
+            #lookup
+        }
+
+        /// Try to derive a currency from the given ASCII string slice, in any case (e.g. `"usd"`).
+        #vis const fn from_str_ignore_case(value: &str) -> Result<Self, #error> {
+            if value.len() != 3 {
+                return Err(#error::InvalidLength);
+            }
+
+            if !value.is_ascii() {
+                return Err(#error::InvalidCharset);
+            }
+
+            let bytes = value.as_bytes();
+            let upper = [
+                bytes[0].to_ascii_uppercase(),
+                bytes[1].to_ascii_uppercase(),
+                bytes[2].to_ascii_uppercase(),
+            ];
+
+            match ::core::str::from_utf8(&upper) {
+                Ok(upper) => Self::from_str_slice(upper),
+                Err(_) => Err(#error::InvalidCharset),
+            }
+        }
+
+        /// The string code for this currency value.
+        #vis const fn as_str(&self) -> &'static str {
+            #as_str
+        }
+    }
+}
+
+/// Generate the lenient parser, which needs both the string and numeric lookups.
+fn build_lenient(config: &Config) -> TokenStream {
+    let vis = &config.vis;
+    let error = &config.error_name;
+
+    quote::quote! {
+        /// Try to derive a currency from the given string slice, however it is formatted.
+        ///
+        /// Surrounding ASCII whitespace is ignored, and the value may be a three-character code in
+        /// any case (e.g. `" usd "`), or a numeric code of up to three digits (e.g. `"840"` or
+        /// `"008"`).
+        #vis const fn from_str_lenient(value: &str) -> Result<Self, #error> {
+            let value = value.trim_ascii();
+            let bytes = value.as_bytes();
+
+            if bytes.is_empty() {
+                return Err(#error::InvalidLength);
+            }
+
+            if !bytes[0].is_ascii_digit() {
+                return Self::from_str_ignore_case(value);
+            }
+
+            if bytes.len() > 3 {
+                return Err(#error::InvalidLength);
+            }
+
+            let mut number = 0;
+            let mut i = 0;
+
+            while i < bytes.len() {
+                if !bytes[i].is_ascii_digit() {
+                    return Err(#error::InvalidCode);
+                }
+
+                number = number * 10 + (bytes[i] - b'0') as u16;
+                i += 1;
+            }
+
+            Self::from_u16(number)
+        }
+    }
+}
+
+/// Generate the body of an accessor, which reads the given field of the info table when it is
+/// generated, uses the given table lookup in table mode, or matches on every variant otherwise.
+fn build_accessor<T: ToTokens>(
+    id: &[Ident],
+    values: &[T],
+    field: &str,
+    table: TokenStream,
+    config: &Config,
+) -> TokenStream {
+    if config.methods.info {
+        let field = Ident::new(field, Span::call_site());
+        quote::quote! { self.info().#field }
+    } else if config.lookup == Lookup::Table {
+        table
+    } else {
+        quote::quote! {
+            match self {
+                #(
+                    Self::#id => #values,
+                )*
+            }
+        }
+    }
+}
+
+/// The minor unit of each entry, as an `Option<u8>` expression.
+fn minor_unit_tokens(entryset: &EntrySet) -> Vec<TokenStream> {
+    entryset
+        .minor_unit()
+        .iter()
+        .map(|unit| {
+            unit.map_or_else(
+                || quote::quote! { None },
+                |unit| quote::quote! { Some(#unit) },
+            )
+        })
+        .collect()
+}
+
+/// Generate the country lookup methods.
+fn build_country(entryset: &EntrySet, config: &Config) -> TokenStream {
+    let id = entryset.ident();
+    let country_idents = entryset.country_idents();
+    let country_ident = entryset.country_ident();
+    let currency_ident = entryset.currency_ident();
+    let vis = &config.vis;
+
+    quote::quote! {
+        /// The countries using this currency, by their alpha-2 code.
+        #vis const fn alpha2_countries(self) -> &'static [iso3166_static::Alpha2] {
+            match self {
+                #(
+                    Self::#id => &[#(iso3166_static::Alpha2::#country_idents),*],
+                )*
+            }
+        }
+
+        /// The primary currency for the given country, if there is one.
+        #vis const fn from_numeric_country(value: iso3166_static::Numeric) -> Option<Self> {
+            match value {
+                #(
+                    iso3166_static::Numeric::#country_ident => Some(Self::#currency_ident),
+                )*
+
+                _ => None,
+            }
+        }
+
+        /// The primary currency for the given country, if there is one.
+        #vis const fn from_alpha2_country(value: iso3166_static::Alpha2) -> Option<Self> {
+            match value {
+                #(
+                    iso3166_static::Alpha2::#country_ident => Some(Self::#currency_ident),
+                )*
+
+                _ => None,
+            }
+        }
+
+        /// The primary currency for the given country, if there is one.
+        #vis const fn from_alpha3_country(value: iso3166_static::Alpha3) -> Option<Self> {
+            match value {
+                #(
+                    iso3166_static::Alpha3::#country_ident => Some(Self::#currency_ident),
+                )*
+
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Generate the dense index methods.
+fn build_index(entryset: &EntrySet, config: &Config) -> TokenStream {
+    let id = entryset.ident();
+    let index = 0..id.len();
+    let vis = &config.vis;
+
+    let (index, from_index) = match config.lookup {
+        Lookup::Match => (
+            quote::quote! {
+                match self {
+                    #(
+                        Self::#id => #index,
+                    )*
+                }
+            },
+            quote::quote! {
+                if index < Self::COUNT {
+                    Some(Self::ALL[index])
+                } else {
+                    None
+                }
+            },
+        ),
+        Lookup::Table => {
+            let tables = Tables::new(config);
+            (tables.index(), tables.index_lookup())
+        }
+    };
+
+    quote::quote! {
+        /// Every currency, in numeric code order.
+        #vis const ALL: [Self; Self::COUNT] = [
+            #(
+                Self::#id,
+            )*
+        ];
+
+        /// The position of this currency in [`Self::ALL`], from `0` to `COUNT - 1`.
+        #vis const fn index(self) -> usize {
+            #index
+        }
+
+        /// The currency at the given position in [`Self::ALL`], if there is one.
+        #vis const fn from_index(index: usize) -> Option<Self> {
+            #from_index
+        }
+    }
+}
+
+/// Generate the info struct and kind enum.
+fn build_info_types(config: &Config) -> TokenStream {
+    let vis = &config.vis;
+    let ty = &config.type_name;
+    let info = &config.info_name;
+    let kind = &config.kind_name;
+
+    quote::quote! {
+        /// The kind of an ISO 4217 currency code.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        #vis enum #kind {
+            /// A currency which is legal tender in at least one country.
+            LegalTender,
+            /// A fund code, used alongside a currency for specific purposes.
+            Fund,
+            /// A unit of account, such as a bond market unit or special drawing right.
+            UnitOfAccount,
+            /// A precious metal.
+            PreciousMetal,
+            /// The code reserved for testing.
+            Testing,
+            /// The code used for transactions where no currency is involved.
+            NoCurrency,
+        }
+
+        /// Static information about an ISO 4217 currency.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[non_exhaustive]
+        #vis struct #info {
+            /// The currency this information describes.
+            pub currency: #ty,
+            /// The three-character code.
+            pub code: &'static str,
+            /// The numeric code.
+            pub number: u16,
+            /// The numeric code, zero-padded to three digits.
+            pub numeric: &'static str,
+            /// The name of the currency.
+            pub name: &'static str,
+            /// The minor unit decimal place, if there is a minor unit.
+            pub minor_unit: Option<u8>,
+            /// Whether this currency code represents a fund or not.
+            pub is_fund: bool,
+            /// The kind of currency.
+            pub kind: #kind,
+            /// The entities using this currency, as named in the data.
+            pub countries: &'static [&'static str],
+        }
+    }
+}
+
+/// Generate the info table and its accessor.
+fn build_info(entryset: &EntrySet, config: &Config) -> TokenStream {
+    let id = entryset.ident();
+    let code = entryset.currency();
+    let number = entryset.number();
+    let numeric = number.iter().map(|number| format!("{number:03}"));
+    let name = entryset.name();
+    let minor_unit = minor_unit_tokens(entryset);
+    let is_fund = entryset.is_fund();
+    let kind = entryset.kind();
+    let countries = entryset.countries();
+    let vis = &config.vis;
+    let ty = &config.type_name;
+    let info = &config.info_name;
+    let kind_name = &config.kind_name;
+
+    quote::quote! {
+        /// Static information about this currency.
+        #vis const fn info(&self) -> &'static #info {
+            static INFO: [#info; #ty::COUNT] = [
+                #(
+                    #info {
+                        currency: #ty::#id,
+                        code: #code,
+                        number: #number,
+                        numeric: #numeric,
+                        name: #name,
+                        minor_unit: #minor_unit,
+                        is_fund: #is_fund,
+                        kind: #kind_name::#kind,
+                        countries: &[#(#countries),*],
+                    },
+                )*
+            ];
+
+            &INFO[self.index()]
+        }
+    }
+}
+
+/// Generate the dataset metadata constants.
+fn build_metadata(metadata: &Metadata, config: &Config) -> TokenStream {
+    let vis = &config.vis;
+    let year = metadata.published.year();
+    let month = metadata.published.month();
+    let day = metadata.published.day();
+    let sha256 = &metadata.sha256;
+    let version = env!("CARGO_PKG_VERSION");
+
+    quote::quote! {
+        /// The publication date of the ISO 4217 data this enum was generated from.
+        #vis const PUBLISHED: ::chrono::NaiveDate =
+            ::chrono::NaiveDate::from_ymd_opt(#year, #month, #day).expect("valid publication date");
+
+        /// The lowercase hex SHA-256 digest of the data file this enum was generated from.
+        #vis const SOURCE_SHA256: &'static str = #sha256;
+
+        /// The version of `iso4217-codegen` which generated this enum.
+        #vis const GENERATOR_VERSION: &'static str = #version;
+    }
+}
+
+/// Generate the inherent methods.
+fn build_impl(entryset: &EntrySet, config: &Config) -> TokenStream {
+    let id = entryset.ident();
+    let vis = &config.vis;
+    let ty = &config.type_name;
+    let methods = config.methods;
+    let tables = Tables::new(config);
+
+    let count = entryset.ident().len();
+
+    let mut body = quote::quote! {
+        /// The number of currencies in this enum.
+        #vis const COUNT: usize = #count;
+    };
+
+    if let Some(metadata) = &config.metadata {
+        body.extend(build_metadata(metadata, config));
+    }
+
+    if methods.numeric {
+        body.extend(build_numeric(entryset, config));
+    }
+
+    if methods.string {
+        body.extend(build_string(entryset, config));
+    }
+
+    if methods.string && methods.numeric {
+        body.extend(build_lenient(config));
+    }
+
+    if methods.name {
+        let name = build_accessor(id, entryset.name(), "name", tables.name(), config);
+        body.extend(quote::quote! {
+            /// The name of this currency.
+            #vis const fn name(&self) -> &'static str {
+                #name
+            }
+        });
+    }
+
+    if methods.fund {
+        let is_fund = build_accessor(id, entryset.is_fund(), "is_fund", tables.is_fund(), config);
+        body.extend(quote::quote! {
+            /// Whether this currency code represents a fund or not.
+            #vis const fn is_fund(&self) -> bool {
+                #is_fund
+            }
+        });
+    }
+
+    if methods.minor_unit {
+        let minor_unit = build_accessor(
+            id,
+            &minor_unit_tokens(entryset),
+            "minor_unit",
+            tables.minor_unit(),
+            config,
+        );
+        body.extend(quote::quote! {
+            /// The minor unit decimal place, if there is a minor unit.
+            #vis const fn minor_unit(&self) -> Option<u8> {
+                #minor_unit
+            }
+        });
+    }
+
+    if methods.country {
+        body.extend(build_country(entryset, config));
+    }
+
+    if methods.index {
+        body.extend(build_index(entryset, config));
+    }
+
+    if methods.info {
+        body.extend(build_info(entryset, config));
+    }
+
+    quote::quote! {
+        impl #ty {
+            #body
+        }
+    }
+}
+
+/// Generate conversions to and from a complete enum generated from the same data.
+fn build_convert(entryset: &EntrySet, config: &Config, convert: &Path) -> TokenStream {
+    let id = entryset.ident();
+    let number = entryset.number();
+    let ty = &config.type_name;
+    let error = &config.error_name;
+
+    quote::quote! {
+        impl From<#ty> for #convert {
+            fn from(value: #ty) -> Self {
+                match #convert::from_u16(value as u16) {
+                    Ok(currency) => currency,
+                    Err(_) => unreachable!("every currency is in the complete enum"),
+                }
+            }
+        }
+
+        impl TryFrom<#convert> for #ty {
+            type Error = #error;
+
+            fn try_from(value: #convert) -> Result<Self, Self::Error> {
+                match value as u16 {
+                    #(
+                        #number => Ok(Self::#id),
+                    )*
+                    _ => Err(#error::InvalidCode),
+                }
+            }
+        }
+    }
+}
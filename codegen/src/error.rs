@@ -0,0 +1,49 @@
+//! Error handling
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// An error encountered while generating code.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Error {
+    /// The option which caused the error, if any.
+    param: Option<&'static str>,
+
+    /// A description of the error.
+    message: String,
+}
+
+impl Error {
+    /// Create a new error caused by the given option.
+    pub(crate) fn new(param: Option<&'static str>, message: impl Into<String>) -> Self {
+        Self {
+            param,
+            message: message.into(),
+        }
+    }
+
+    /// The option which caused this error, if any.
+    ///
+    /// This is the name of the [`Builder`](crate::Builder) method, which matches the name of the
+    /// `generate!` macro parameter (e.g. `xml`, `overlay` or `vis`).
+    #[must_use]
+    pub fn param(&self) -> Option<&'static str> {
+        self.param
+    }
+
+    /// A description of the error.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.message)
+    }
+}
+
+impl StdError for Error {}
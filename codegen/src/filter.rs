@@ -0,0 +1,107 @@
+//! Entry filtering
+
+use crate::{Error, recordset};
+use iso4217_parser::{CurrencyEntry, CurrencyName};
+use std::collections::BTreeSet;
+
+/// The entries which should be emitted by the macro.
+#[derive(Clone, Debug)]
+pub(crate) struct Filter {
+    /// Whether fund codes are emitted.
+    pub funds: bool,
+    /// Whether codes starting with `X` are emitted.
+    pub x_codes: bool,
+    /// The only codes to emit.
+    pub include: Option<BTreeSet<String>>,
+    /// Codes which should not be emitted.
+    pub exclude: Option<BTreeSet<String>>,
+    /// The countries whose currencies should be emitted.
+    pub countries: Option<BTreeSet<String>>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            funds: true,
+            x_codes: true,
+            include: None,
+            exclude: None,
+            countries: None,
+        }
+    }
+}
+
+/// Check that every value in the given set is known, reporting the first one which isn't.
+fn check_known(
+    param: &'static str,
+    values: Option<&BTreeSet<String>>,
+    known: &BTreeSet<String>,
+) -> Result<(), Error> {
+    if let Some(values) = values
+        && let Some(unknown) = values.iter().find(|value| !known.contains(*value))
+    {
+        let message = format!("The `{param}` parameter names {unknown}, which is not in the data");
+        return Err(Error::new(Some(param), message));
+    }
+
+    Ok(())
+}
+
+impl Filter {
+    /// Remove the entries which should not be emitted.
+    ///
+    /// Countries select every entry for the codes they use, so country lookups for the remaining
+    /// codes are unaffected.
+    pub(crate) fn apply(&self, entries: &mut Vec<CurrencyEntry>) -> Result<(), Error> {
+        let codes = entries
+            .iter()
+            .filter_map(CurrencyEntry::currency)
+            .map(str::to_owned)
+            .collect::<BTreeSet<_>>();
+
+        check_known("include", self.include.as_ref(), &codes)?;
+        check_known("exclude", self.exclude.as_ref(), &codes)?;
+
+        let country_codes = if let Some(countries) = &self.countries {
+            let mut known = BTreeSet::new();
+            let mut selected = BTreeSet::new();
+
+            for entry in entries.iter() {
+                let country = recordset::country_id(entry.country());
+                if countries.contains(&country)
+                    && let Some(currency) = entry.currency()
+                {
+                    selected.insert(currency.to_owned());
+                }
+                known.insert(country);
+            }
+
+            check_known("countries", self.countries.as_ref(), &known)?;
+            Some(selected)
+        } else {
+            None
+        };
+
+        entries.retain(|entry| {
+            let Some(currency) = entry.currency() else {
+                return false;
+            };
+
+            (self.funds || !entry.name().is_some_and(CurrencyName::is_fund))
+                && (self.x_codes || !currency.starts_with('X'))
+                && self
+                    .include
+                    .as_ref()
+                    .is_none_or(|include| include.contains(currency))
+                && self
+                    .exclude
+                    .as_ref()
+                    .is_none_or(|exclude| !exclude.contains(currency))
+                && country_codes
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(currency))
+        });
+
+        Ok(())
+    }
+}
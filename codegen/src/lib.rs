@@ -0,0 +1,19 @@
+//! Code Generation for ISO 4217 Data
+
+#![doc = include_str!("../README.md")]
+
+mod builder;
+mod emit;
+mod error;
+mod filter;
+mod load;
+mod lookup;
+mod naming;
+mod recordset;
+
+pub use crate::{
+    builder::{Builder, Format, Methods},
+    error::Error,
+    lookup::Lookup,
+    naming::Strategy,
+};
@@ -0,0 +1,119 @@
+//! Input loading
+
+use crate::{Error, Format, naming::Naming, naming::Strategy};
+use chrono::NaiveDate;
+use iso4217_parser::{CurrencyDoc, CurrencyTable, Overlay};
+use quick_xml::de;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs::{self, File},
+    io::BufReader,
+    path::Path,
+};
+
+/// Load the currency table from the given data file, along with its publication date, if known.
+pub(crate) fn table(
+    path: &Path,
+    format: Format,
+) -> Result<(CurrencyTable, Option<NaiveDate>), Error> {
+    let param = Some(format.param());
+    let file = File::open(path).map_err(|error| {
+        let message = format!("Could not open `{}` file: {error}", format.param());
+        Error::new(param, message)
+    })?;
+
+    let reader = BufReader::new(file);
+
+    match format {
+        Format::Xml => de::from_reader::<_, CurrencyDoc>(reader)
+            .map(|doc| (doc.table().clone(), doc.published().ok()))
+            .map_err(|error| {
+                let message = format!("Could not parse XML file: {error}");
+                Error::new(param, message)
+            }),
+        Format::Json => CurrencyTable::read_json_lines(reader)
+            .map(|table| (table, None))
+            .map_err(|error| {
+                let message = format!("Could not parse JSON file: {error}");
+                Error::new(param, message)
+            }),
+        Format::Csv => CurrencyTable::read_csv(reader)
+            .map(|table| (table, None))
+            .map_err(|error| {
+                let message = format!("Could not parse CSV file: {error}");
+                Error::new(param, message)
+            }),
+    }
+}
+
+/// Apply the overlay file at the given path to the table.
+pub(crate) fn overlay(table: &mut CurrencyTable, path: &Path) -> Result<(), Error> {
+    let file = File::open(path).map_err(|error| {
+        let message = format!("Could not open `overlay` file: {error}");
+        Error::new(Some("overlay"), message)
+    })?;
+
+    Overlay::from_json_lines(BufReader::new(file))
+        .and_then(|overlay| table.apply(&overlay))
+        .map_err(|error| {
+            let message = format!("Invalid `overlay` file, {error}");
+            Error::new(Some("overlay"), message)
+        })
+}
+
+/// Load the per-code variant identifiers at the given path, checking they refer to known codes.
+pub(crate) fn naming(
+    strategy: Strategy,
+    idents: Option<&Path>,
+    table: &CurrencyTable,
+) -> Result<Naming, Error> {
+    let Some(path) = idents else {
+        return Ok(Naming::new(strategy, BTreeMap::default()));
+    };
+
+    let file = File::open(path).map_err(|error| {
+        let message = format!("Could not open `idents` file: {error}");
+        Error::new(Some("idents"), message)
+    })?;
+
+    let overrides = serde_json::from_reader::<_, BTreeMap<String, String>>(BufReader::new(file))
+        .map_err(|error| {
+            let message = format!("Could not parse `idents` file: {error}");
+            Error::new(Some("idents"), message)
+        })?;
+
+    let naming = Naming::new(strategy, overrides);
+
+    for currency in naming.overridden() {
+        if !table
+            .entries()
+            .iter()
+            .any(|entry| entry.currency() == Some(currency))
+        {
+            let message = format!("The `idents` file names {currency}, which is not in the data");
+            return Err(Error::new(Some("idents"), message));
+        }
+    }
+
+    Ok(naming)
+}
+
+/// The lowercase hex SHA-256 digest of the given data file.
+pub(crate) fn sha256(path: &Path, format: Format) -> Result<String, Error> {
+    let bytes = fs::read(path).map_err(|error| {
+        let message = format!("Could not read `{}` file: {error}", format.param());
+        Error::new(Some(format.param()), message)
+    })?;
+
+    let digest = Sha256::digest(bytes);
+    let mut retval = String::with_capacity(digest.len() * 2);
+
+    for byte in digest {
+        // Writing to a string can't fail.
+        let _ = write!(retval, "{byte:02x}");
+    }
+
+    Ok(retval)
+}
@@ -0,0 +1,373 @@
+//! Table-driven lookups
+
+use crate::{Error, emit::Config, recordset::EntrySet};
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Literal, Span, TokenStream};
+use std::str::FromStr;
+use syn::Ident;
+
+/// How the generated methods look up values.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Lookup {
+    /// A `match` over every variant in each method, which is fastest.
+    #[default]
+    Match,
+    /// Binary searches over compact, sorted static tables shared by every method, which is
+    /// smallest.
+    Table,
+}
+
+impl FromStr for Lookup {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "match" => Ok(Self::Match),
+            "table" => Ok(Self::Table),
+            _ => Err(Error::new(
+                Some("lookup"),
+                "`lookup` must be one of \"match\" or \"table\"",
+            )),
+        }
+    }
+}
+
+/// Check the entries can be stored in the tables.
+pub(crate) fn validate(entryset: &EntrySet) -> Result<(), Error> {
+    if let Some(currency) = entryset
+        .currency()
+        .iter()
+        .find(|currency| currency.len() != 3 || !currency.bytes().all(|b| b.is_ascii_uppercase()))
+    {
+        let message = format!(
+            "`lookup = \"table\"` requires three uppercase letter codes, which {currency} is not"
+        );
+        return Err(Error::new(Some("lookup"), message));
+    }
+
+    if entryset.name().iter().map(String::len).sum::<usize>() > usize::from(u16::MAX) {
+        return Err(Error::new(
+            Some("lookup"),
+            "`lookup = \"table\"` requires the names to total at most 65535 bytes",
+        ));
+    }
+
+    Ok(())
+}
+
+/// The key of a three uppercase letter code, which sorts in the same order as the code.
+fn code_key(code: &str) -> u16 {
+    code.bytes()
+        .fold(0, |key, byte| (key << 5) | u16::from(byte - b'A'))
+}
+
+/// The module-level items which back the lookups of a generated enum.
+pub(crate) struct Tables<'a> {
+    /// The generator configuration.
+    config: &'a Config,
+    /// The prefix of the static table names.
+    prefix: String,
+    /// The name of the function which finds the position of a numeric code.
+    position: Ident,
+}
+
+impl<'a> Tables<'a> {
+    /// Create the table names for the given configuration.
+    pub(crate) fn new(config: &'a Config) -> Self {
+        let type_name = config.type_name.to_string();
+
+        Self {
+            config,
+            prefix: type_name.to_shouty_snake_case(),
+            position: Ident::new(
+                &format!("{}_position", type_name.to_snake_case()),
+                Span::mixed_site(),
+            ),
+        }
+    }
+
+    /// The name of the given table.
+    fn table(&self, name: &str) -> Ident {
+        Ident::new(&format!("{}_{name}", self.prefix), Span::mixed_site())
+    }
+
+    /// Whether accessors are served by the `info()` table instead.
+    fn info(&self) -> bool {
+        self.config.methods.info
+    }
+
+    /// Whether the tables sorted by numeric code are used.
+    fn numbers(&self) -> bool {
+        let methods = self.config.methods;
+
+        methods.numeric
+            || methods.index
+            || (!self.info()
+                && (methods.string || methods.name || methods.fund || methods.minor_unit))
+    }
+
+    /// Generate the static tables, and the binary search over numeric codes.
+    pub(crate) fn build(&self, entryset: &EntrySet) -> TokenStream {
+        let methods = self.config.methods;
+        let ty = &self.config.type_name;
+        let id = entryset.ident();
+        let count = Literal::usize_unsuffixed(id.len());
+        let mut retval = TokenStream::new();
+
+        if self.numbers() {
+            let numbers = self.table("NUMBERS");
+            let number = entryset.number();
+            let position = &self.position;
+
+            retval.extend(quote::quote! {
+                /// The numeric codes, in order.
+                static #numbers: [u16; #count] = [#(#number),*];
+
+                /// The position of the given numeric code in the tables, or where it would be.
+                const fn #position(number: u16) -> usize {
+                    let (mut low, mut high) = (0, #numbers.len());
+
+                    while low < high {
+                        let mid = low + (high - low) / 2;
+
+                        if #numbers[mid] < number {
+                            low = mid + 1;
+                        } else {
+                            high = mid;
+                        }
+                    }
+
+                    low
+                }
+            });
+        }
+
+        if methods.numeric || methods.index {
+            let variants = self.table("VARIANTS");
+
+            retval.extend(quote::quote! {
+                /// The variants, in numeric code order.
+                static #variants: [#ty; #count] = [#(#ty::#id),*];
+            });
+        }
+
+        if methods.string {
+            let keys = self.table("CODE_KEYS");
+            let code_variants = self.table("CODE_VARIANTS");
+
+            let mut by_code = entryset
+                .currency()
+                .iter()
+                .zip(id)
+                .map(|(currency, id)| (code_key(currency), id))
+                .collect::<Vec<_>>();
+            by_code.sort_by_key(|(key, _id)| *key);
+            let (key, code_id): (Vec<_>, Vec<_>) = by_code.into_iter().unzip();
+
+            retval.extend(quote::quote! {
+                /// The five-bit-per-letter keys of the codes, in order.
+                static #keys: [u16; #count] = [#(#key),*];
+
+                /// The variants, in code order.
+                static #code_variants: [#ty; #count] = [#(#ty::#code_id),*];
+            });
+        }
+
+        if methods.string && !self.info() {
+            let codes = self.table("CODES");
+            let code = entryset.currency().concat();
+
+            retval.extend(quote::quote! {
+                /// The codes, concatenated in numeric code order.
+                static #codes: &str = #code;
+            });
+        }
+
+        if methods.name && !self.info() {
+            let names = self.table("NAMES");
+            let name_ends = self.table("NAME_ENDS");
+            let name = entryset.name().concat();
+            let end = entryset
+                .name()
+                .iter()
+                .scan(0, |end, name| {
+                    *end += name.len();
+                    u16::try_from(*end).ok()
+                })
+                .collect::<Vec<_>>();
+
+            retval.extend(quote::quote! {
+                /// The names, concatenated in numeric code order.
+                static #names: &str = #name;
+
+                /// The end of each name in the concatenated names.
+                static #name_ends: [u16; #count] = [#(#end),*];
+            });
+        }
+
+        if methods.fund && !self.info() {
+            let funds = self.table("FUNDS");
+            let bits = entryset
+                .is_fund()
+                .chunks(8)
+                .map(|chunk| {
+                    chunk.iter().enumerate().fold(0_u8, |byte, (bit, is_fund)| {
+                        byte | (u8::from(*is_fund) << bit)
+                    })
+                })
+                .collect::<Vec<_>>();
+            let len = Literal::usize_unsuffixed(bits.len());
+
+            retval.extend(quote::quote! {
+                /// One bit per variant, in numeric code order, set for funds.
+                static #funds: [u8; #len] = [#(#bits),*];
+            });
+        }
+
+        if methods.minor_unit && !self.info() {
+            let minor_units = self.table("MINOR_UNITS");
+            let minor_unit = entryset
+                .minor_unit()
+                .iter()
+                .map(|unit| unit.unwrap_or(u8::MAX));
+
+            retval.extend(quote::quote! {
+                /// The minor units, in numeric code order, with `u8::MAX` for none.
+                static #minor_units: [u8; #count] = [#(#minor_unit),*];
+            });
+        }
+
+        retval
+    }
+
+    /// The position of the given enum value in the tables.
+    fn position_of(&self, value: &TokenStream) -> TokenStream {
+        let position = &self.position;
+        quote::quote! { #position(#value as u16) }
+    }
+
+    /// The body of `from_u16()`.
+    pub(crate) fn numeric_lookup(&self) -> TokenStream {
+        let numbers = self.table("NUMBERS");
+        let variants = self.table("VARIANTS");
+        let position = &self.position;
+        let error = &self.config.error_name;
+
+        quote::quote! {
+            let index = #position(value);
+
+            if index < Self::COUNT && #numbers[index] == value {
+                Ok(#variants[index])
+            } else {
+                Err(#error::InvalidCode)
+            }
+        }
+    }
+
+    /// The lookup in `from_str_slice()`, after the length and charset have been checked.
+    pub(crate) fn code_lookup(&self) -> TokenStream {
+        let keys = self.table("CODE_KEYS");
+        let code_variants = self.table("CODE_VARIANTS");
+        let error = &self.config.error_name;
+
+        quote::quote! {
+            let bytes = value.as_bytes();
+            let mut key = 0;
+            let mut i = 0;
+
+            while i < 3 {
+                if !bytes[i].is_ascii_uppercase() {
+                    return Err(#error::InvalidCode);
+                }
+
+                key = (key << 5) | (bytes[i] - b'A') as u16;
+                i += 1;
+            }
+
+            let (mut low, mut high) = (0, Self::COUNT);
+
+            while low < high {
+                let mid = low + (high - low) / 2;
+
+                if #keys[mid] < key {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+
+            if low < Self::COUNT && #keys[low] == key {
+                Ok(#code_variants[low])
+            } else {
+                Err(#error::InvalidCode)
+            }
+        }
+    }
+
+    /// The body of `as_str()`.
+    pub(crate) fn as_str(&self) -> TokenStream {
+        let codes = self.table("CODES");
+        let position = self.position_of(&quote::quote! { *self });
+
+        quote::quote! {
+            let (_, code) = #codes.split_at(#position * 3);
+            code.split_at(3).0
+        }
+    }
+
+    /// The body of `name()`.
+    pub(crate) fn name(&self) -> TokenStream {
+        let names = self.table("NAMES");
+        let name_ends = self.table("NAME_ENDS");
+        let position = self.position_of(&quote::quote! { *self });
+
+        quote::quote! {
+            let index = #position;
+            let start = if index == 0 { 0 } else { #name_ends[index - 1] as usize };
+            let (_, name) = #names.split_at(start);
+            name.split_at(#name_ends[index] as usize - start).0
+        }
+    }
+
+    /// The body of `is_fund()`.
+    pub(crate) fn is_fund(&self) -> TokenStream {
+        let funds = self.table("FUNDS");
+        let position = self.position_of(&quote::quote! { *self });
+
+        quote::quote! {
+            let index = #position;
+            #funds[index / 8] & (1 << (index % 8)) != 0
+        }
+    }
+
+    /// The body of `minor_unit()`.
+    pub(crate) fn minor_unit(&self) -> TokenStream {
+        let minor_units = self.table("MINOR_UNITS");
+        let position = self.position_of(&quote::quote! { *self });
+
+        quote::quote! {
+            match #minor_units[#position] {
+                u8::MAX => None,
+                unit => Some(unit),
+            }
+        }
+    }
+
+    /// The body of `index()`.
+    pub(crate) fn index(&self) -> TokenStream {
+        self.position_of(&quote::quote! { self })
+    }
+
+    /// The body of `from_index()`.
+    pub(crate) fn index_lookup(&self) -> TokenStream {
+        let variants = self.table("VARIANTS");
+
+        quote::quote! {
+            if index < Self::COUNT {
+                Some(#variants[index])
+            } else {
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Variant naming
+
+use crate::Error;
+use heck::ToPascalCase;
+use std::{collections::BTreeMap, str::FromStr};
+
+/// How enum variant identifiers are derived from a currency.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Strategy {
+    /// The pascal-cased currency name (e.g. `UsDollar`).
+    #[default]
+    Name,
+    /// The currency code as-is (e.g. `USD`).
+    Code,
+    /// The pascal-cased currency code (e.g. `Usd`).
+    CodePascal,
+}
+
+impl FromStr for Strategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "code" => Ok(Self::Code),
+            "code_pascal" => Ok(Self::CodePascal),
+            _ => Err(Error::new(
+                Some("naming"),
+                "`naming` must be one of \"name\", \"code\" or \"code_pascal\"",
+            )),
+        }
+    }
+}
+
+/// The naming strategy, along with any per-code identifier overrides.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Naming {
+    /// The strategy used for codes without an override.
+    strategy: Strategy,
+
+    /// Variant identifiers by currency code.
+    overrides: BTreeMap<String, String>,
+}
+
+impl Naming {
+    /// Create a new naming scheme.
+    pub(crate) fn new(strategy: Strategy, overrides: BTreeMap<String, String>) -> Self {
+        Self {
+            strategy,
+            overrides,
+        }
+    }
+
+    /// The strategy used for codes without an override.
+    pub(crate) fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    /// The currency codes which have an identifier override.
+    pub(crate) fn overridden(&self) -> impl Iterator<Item = &str> {
+        self.overrides.keys().map(String::as_str)
+    }
+
+    /// The variant identifier for the given currency code and name.
+    pub(crate) fn variant(&self, currency: &str, name: &str) -> String {
+        if let Some(ident) = self.overrides.get(currency) {
+            return ident.clone();
+        }
+
+        match self.strategy {
+            Strategy::Name => name.trim().to_pascal_case(),
+            Strategy::Code => currency.to_owned(),
+            Strategy::CodePascal => currency.to_pascal_case(),
+        }
+    }
+}
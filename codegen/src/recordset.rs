@@ -0,0 +1,340 @@
+//! Struct-of-Arrays data set
+
+use crate::{Error, naming::Naming};
+use heck::ToPascalCase;
+use iso4217_parser::CurrencyEntry;
+use proc_macro2::Span;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+};
+use syn::{Ident, LitByteStr};
+
+#[derive(Clone, Default)]
+pub(crate) struct EntrySet {
+    /// The documentation string.
+    doc: Vec<String>,
+
+    /// The enumeration variant identifier.
+    ident: Vec<Ident>,
+
+    /// The ASCII code.
+    currency: Vec<String>,
+
+    /// The ASCII code, as bytes.
+    bytes: Vec<LitByteStr>,
+
+    /// The numeric code.
+    number: Vec<u16>,
+
+    /// The name of the currency.
+    name: Vec<String>,
+
+    /// Whether a value is a fund or not.
+    is_fund: Vec<bool>,
+
+    /// The number of decimal places in the minor unit.
+    minor_unit: Vec<Option<u8>>,
+
+    /// The kind of currency, as a variant of the generated kind enum.
+    kind: Vec<Ident>,
+
+    /// The names of the entities using the currency.
+    countries: Vec<Vec<String>>,
+
+    /// The iso3166 identifiers of the countries using the currency.
+    country_idents: Vec<Vec<Ident>>,
+
+    /// The iso3166 country identifier.
+    country_ident: Vec<Ident>,
+
+    /// Currency number by country ident.
+    currency_ident: Vec<Ident>,
+}
+
+/// The codes ISO 4217 reserves for precious metals.
+const PRECIOUS_METALS: &[&str] = &["XAG", "XAU", "XPD", "XPT"];
+
+/// The code ISO 4217 reserves for testing.
+const TESTING: &str = "XTS";
+
+/// The code ISO 4217 reserves for transactions where no currency is involved.
+const NO_CURRENCY: &str = "XXX";
+
+const NON_COUNTRIES: &[&str] = &[
+    "ArabMonetaryFund",
+    "EuropeanUnion",
+    "InternationalMonetaryFundImf",
+    "MemberCountriesOfTheAfricanDevelopmentBankGroup",
+    "SistemaUnitarioDeCompensacionRegionalDePagosSucre",
+];
+
+/// Parse a generated identifier, reporting the entry it was generated for if it is invalid.
+fn parse_ident(value: &str, param: &'static str, entry: &str) -> Result<Ident, Error> {
+    if !value.is_ascii() {
+        let message = format!("Invalid non-ASCII enum variant `{value}` generated for {entry}");
+        return Err(Error::new(Some(param), message));
+    }
+
+    syn::parse_str::<Ident>(value).map_err(|_error| {
+        let message = format!("Invalid enum variant `{value}` generated for {entry}");
+        Error::new(Some(param), message)
+    })
+}
+
+/// The `iso3166-static` variant name for the given country name from the data.
+pub(crate) fn country_id(country: &str) -> String {
+    country
+        .replace("(THE)", "")
+        .replace("(PLURINATIONAL STATE OF)", "")
+        .trim()
+        .to_pascal_case()
+        .replace("ÅlandIslands", "AlandIslands")
+        .replace("CôteDIvoire", "CoteDIvoire")
+        .replace("Curaçao", "Curacao")
+        .replace(
+            "CongoTheDemocraticRepublicOfThe",
+            "DemocraticRepublicOfTheCongo",
+        )
+        .replace("IranIslamicRepublicOf", "Iran")
+        .replace("KoreaTheDemocraticPeopleSRepublicOf", "NorthKorea")
+        .replace("KoreaTheRepublicOf", "SouthKorea")
+        .replace("LaoPeopleSDemocraticRepublic", "Laos")
+        .replace("MicronesiaFederatedStatesOf", "Micronesia")
+        .replace("MoldovaTheRepublicOf", "Moldova")
+        .replace("Réunion", "Reunion")
+        .replace("RussianFederation", "Russia")
+        .replace("SaintBarthélemy", "SaintBarthelemy")
+        .replace("SyrianArabRepublic", "Syria")
+        .replace("TaiwanProvinceOfChina", "Taiwan")
+        .replace("TanzaniaUnitedRepublicOf", "Tanzania")
+        .replace(
+            "UnitedKingdomOfGreatBritainAndNorthernIreland",
+            "UnitedKingdom",
+        )
+        .replace("Türki̇ye", "Turkey")
+        .replace("VenezuelaBolivarianRepublicOf", "Venezuela")
+        .replace("VirginIslandsBritish", "BritishVirginIslands")
+}
+
+impl EntrySet {
+    /// Build an entry set from a slice of entries
+    ///
+    /// Errors are attributed to the given option, which should be that of the data file.
+    pub(crate) fn from_entries(
+        entries: &[CurrencyEntry],
+        naming: &Naming,
+        param: &'static str,
+    ) -> Result<Self, Error> {
+        let mut retval = Self::default();
+
+        // collect a map of country strings to currency numbers
+        let mut country_to_currency = BTreeMap::new();
+
+        // The position of each previously seen number.
+        let mut positions = BTreeMap::new();
+
+        // Previously seen numbers which are used by at least one country.
+        let mut legal_tender = BTreeSet::new();
+
+        // Previously generated variant identifiers, and the code they were generated for.
+        let mut idents = BTreeMap::<String, &str>::new();
+
+        for entry in entries {
+            if let Some(currency) = entry.currency()
+                && let Some(number) = entry.number()
+                && let Some(name) = entry.name()
+            {
+                let id = naming.variant(currency, name.name());
+
+                let description = match entry.country() {
+                    "" => format!("the entry for {currency} ({number:03})"),
+                    country => format!("the {country} entry for {currency} ({number:03})"),
+                };
+                let ident = parse_ident(&id, param, &description)?;
+
+                let country_id = country_id(entry.country());
+                let is_country = !country_id.is_empty()
+                    && !country_id.starts_with("Zz")
+                    && !NON_COUNTRIES.iter().any(|&val| val == country_id);
+
+                if is_country {
+                    legal_tender.insert(number);
+                }
+
+                let country_ident = if is_country {
+                    let mut country_ident = parse_ident(&country_id, param, &description)?;
+                    country_ident.set_span(Span::mixed_site());
+                    Some(country_ident)
+                } else {
+                    None
+                };
+
+                if let Some(country_ident) = &country_ident
+                    && !name.is_fund()
+                {
+                    country_to_currency.insert(country_ident.clone(), ident.clone());
+                }
+
+                let position = *positions.entry(number).or_insert(retval.ident.len());
+
+                if position == retval.ident.len() {
+                    if let Some(other) = idents.insert(id.clone(), currency) {
+                        let message = format!(
+                            "The enum variant `{id}` generated for {description} collides with the one generated for {other}"
+                        );
+                        return Err(Error::new(Some(param), message));
+                    }
+
+                    let fund_str = if name.is_fund() { ", Fund" } else { "" };
+                    let doc = format!(" {} ({currency}, {number}{fund_str})", name.name());
+                    let bytes = LitByteStr::new(currency.as_bytes(), Span::mixed_site());
+
+                    retval.doc.push(doc);
+                    retval.ident.push(ident);
+                    retval.currency.push(currency.to_owned());
+                    retval.bytes.push(bytes);
+                    retval.number.push(number);
+                    retval.is_fund.push(name.is_fund());
+                    retval.name.push(name.name().to_owned());
+                    retval.minor_unit.push(entry.minor_unit());
+                    retval.countries.push(Vec::new());
+                    retval.country_idents.push(Vec::new());
+                }
+
+                if let Some(country_ident) = country_ident
+                    && !retval.country_idents[position].contains(&country_ident)
+                {
+                    retval.country_idents[position].push(country_ident);
+                }
+
+                let country = entry.country().trim();
+                if !country.is_empty() && !country.starts_with("ZZ") {
+                    retval.countries[position].push(country.to_owned());
+                }
+            }
+        }
+
+        retval.kind = retval
+            .currency
+            .iter()
+            .zip(&retval.number)
+            .zip(&retval.is_fund)
+            .map(|((currency, number), is_fund)| {
+                let kind = if *is_fund {
+                    "Fund"
+                } else if PRECIOUS_METALS.contains(&currency.as_str()) {
+                    "PreciousMetal"
+                } else if currency == TESTING {
+                    "Testing"
+                } else if currency == NO_CURRENCY {
+                    "NoCurrency"
+                } else if legal_tender.contains(number) {
+                    "LegalTender"
+                } else {
+                    "UnitOfAccount"
+                };
+
+                Ident::new(kind, Span::mixed_site())
+            })
+            .collect();
+
+        for (country_ident, currency_ident) in country_to_currency {
+            retval.country_ident.push(country_ident);
+            retval.currency_ident.push(currency_ident);
+        }
+
+        Ok(retval)
+    }
+
+    /// The documentation strings.
+    pub(crate) fn doc(&self) -> &[String] {
+        &self.doc
+    }
+
+    /// The enum identifiers.
+    pub(crate) fn ident(&self) -> &[Ident] {
+        &self.ident
+    }
+
+    /// The string value
+    pub(crate) fn currency(&self) -> &[String] {
+        &self.currency
+    }
+
+    /// The string value, as bytes.
+    pub(crate) fn bytes(&self) -> &[LitByteStr] {
+        &self.bytes
+    }
+
+    /// The numeric value.
+    pub(crate) fn number(&self) -> &[u16] {
+        &self.number
+    }
+
+    /// The fund status.
+    pub(crate) fn is_fund(&self) -> &[bool] {
+        &self.is_fund
+    }
+
+    /// The number of decimal places in the minor unit.
+    pub(crate) fn minor_unit(&self) -> &[Option<u8>] {
+        &self.minor_unit
+    }
+
+    /// The name of the currency.
+    pub(crate) fn name(&self) -> &[String] {
+        &self.name
+    }
+
+    /// The kind of currency, as a variant of the generated kind enum.
+    pub(crate) fn kind(&self) -> &[Ident] {
+        &self.kind
+    }
+
+    /// The names of the entities using the currency.
+    pub(crate) fn countries(&self) -> &[Vec<String>] {
+        &self.countries
+    }
+
+    /// The iso3166 identifiers of the countries using the currency.
+    pub(crate) fn country_idents(&self) -> &[Vec<Ident>] {
+        &self.country_idents
+    }
+
+    /// A country identifier.
+    ///
+    /// This starts a separately indexed set of fields, alongside `currency_ident`. In particular,
+    /// this field must match one of the enum variants from `iso3166-static`.
+    pub(crate) fn country_ident(&self) -> &[Ident] {
+        &self.country_ident
+    }
+
+    /// A currency identifier.
+    ///
+    /// This starts a separately indexed set of fields, alongside `country_ident`. In particular,
+    /// this field will contain the Country code identifier for this currency.
+    pub(crate) fn currency_ident(&self) -> &[Ident] {
+        &self.currency_ident
+    }
+}
+
+impl Debug for EntrySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EntrySet")
+            .field("doc", &self.doc)
+            .field("ident", &self.ident)
+            .field("currency", &self.currency)
+            .field("bytes", &"...")
+            .field("number", &self.number)
+            .field("name", &self.name)
+            .field("is_fund", &self.is_fund)
+            .field("minor_unit", &self.minor_unit)
+            .field("kind", &self.kind)
+            .field("countries", &self.countries)
+            .field("country_idents", &self.country_idents)
+            .field("country_ident", &self.country_ident)
+            .field("currency_ident", &self.currency_ident)
+            .finish()
+    }
+}
@@ -8,12 +8,14 @@ use proc_macro::TokenStream;
 
 /// Generate ISO 4217 data.
 ///
-/// # Panics
+/// Any problem with the parameters or the data is reported as a compile error, spanned on the
+/// offending parameter.
 ///
-/// If there was an error while parsing or generating data.
+/// The data file, and any overlay or idents files, are included with `include_bytes!()` so the
+/// calling crate is rebuilt when they change.
 #[proc_macro]
 pub fn generate(input: TokenStream) -> TokenStream {
     xml::try_generate(input.into())
-        .expect("Could not generate data")
+        .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
@@ -1,128 +1,226 @@
 //! Configuration
 
+use iso4217_codegen::{Builder, Format, Lookup, Methods, Strategy};
 use proc_macro2::Span;
-use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
 use syn::{Error, Expr, ExprLit, Lit, Meta, MetaNameValue, Result, Token, punctuated::Punctuated};
 
+/// A description of the valid parameters, used in error messages.
+const PARAMS_HELP: &str = concat!(
+    "The valid parameters are `xml`, `json` or `csv`, which should refer to a file relative to ",
+    "the calling crate's `src` directory, `overlay`, which should refer to a JSON lines overlay ",
+    "file in the same directory, `naming`, which should be one of \"name\", \"code\" or ",
+    "\"code_pascal\", `idents`, which should refer to a JSON file of per-code variant names in ",
+    "the same directory, `type_name` and `error_name`, which should be identifiers, `vis`, which ",
+    "should be a visibility such as \"pub(crate)\", `methods`, which should be a comma-separated ",
+    "list of method groups, `lookup`, which should be one of \"match\" or \"table\", `funds` ",
+    "and `x_codes`, which should be booleans, `include`, `exclude` and `countries`, which should ",
+    "be comma-separated lists of codes or country names, `convert`, which should be the path of ",
+    "a generated enum to convert to and from, `metadata`, which should be a boolean, and ",
+    "`zerocopy`, which should refer to the feature name for enabling zerocopy traits."
+);
+
 pub(crate) struct Config {
-    /// The path to the XML file
-    pub xml: PathBuf,
-    /// The span of the xml file path.
-    pub span: Span,
-    /// The feature name to use for zerocopy.
-    pub zerocopy: Option<String>,
+    /// The code generator.
+    pub builder: Builder,
+    /// The span of each parameter's value, by parameter name.
+    pub spans: BTreeMap<String, Span>,
 }
 
 impl Config {
-    /// Parse the literal provided as a path to an XML file
-    fn parse_xml(&mut self, manifest_dir: &str, lit: &Lit) -> Result<()> {
-        if !self.xml.as_os_str().is_empty() {
-            return Err(Error::new_spanned(lit, "Multiple `xml` parameters."));
-        }
+    /// Convert a code generation error into a compile error, spanned on the offending parameter.
+    pub(crate) fn error(&self, error: &iso4217_codegen::Error) -> Error {
+        let span = error
+            .param()
+            .and_then(|param| self.spans.get(param))
+            .copied()
+            .unwrap_or_else(Span::call_site);
+
+        Error::new(span, error.message())
+    }
 
+    /// Parse the literal provided as a path to a file relative to the calling crate's `src` dir
+    fn parse_path(param: &str, manifest_dir: &str, lit: &Lit) -> Result<PathBuf> {
         match lit {
             Lit::Str(lit_str) => {
-                self.xml.push(manifest_dir);
-                self.xml.push("src");
-                self.xml.push(lit_str.value());
+                let mut path = PathBuf::from(manifest_dir);
+                path.push("src");
+                path.push(lit_str.value());
 
-                self.span = lit_str.span();
+                Ok(path)
             }
             val => {
-                return Err(Error::new_spanned(
-                    val,
-                    "`xml` must be a path to an XML file relative to the `src` dir of the calling crate",
-                ));
+                let message = format!(
+                    "`{param}` must be a path to a file relative to the `src` dir of the calling crate"
+                );
+                Err(Error::new_spanned(val, message))
             }
         }
+    }
 
-        Ok(())
+    /// Parse the literal provided as a string
+    fn parse_str(param: &str, lit: &Lit) -> Result<String> {
+        match lit {
+            Lit::Str(lit_str) => Ok(lit_str.value()),
+            val => {
+                let message = format!("`{param}` must be a string-literal");
+                Err(Error::new_spanned(val, message))
+            }
+        }
     }
 
-    /// Parse the literal provided as a zerocopy string (or boolean to enable it)
-    fn parse_zerocopy(&mut self, lit: &Lit) -> Result<()> {
-        if self.zerocopy.is_some() {
-            return Err(Error::new_spanned(lit, "Multiple `zerocopy` parameters."));
+    /// Parse the literal provided as a boolean
+    fn parse_bool(param: &str, lit: &Lit) -> Result<bool> {
+        match lit {
+            Lit::Bool(lit_bool) => Ok(lit_bool.value()),
+            val => {
+                let message = format!("`{param}` must be a boolean");
+                Err(Error::new_spanned(val, message))
+            }
         }
+    }
 
+    /// Parse the literal provided as a comma-separated list of values
+    fn parse_list(param: &str, lit: &Lit) -> Result<Vec<String>> {
+        let Lit::Str(lit_str) = lit else {
+            let message = format!("`{param}` must be a string-literal, comma-separated list");
+            return Err(Error::new_spanned(lit, message));
+        };
+
+        Ok(lit_str
+            .value()
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    /// Parse the literal provided as a zerocopy string (or boolean to enable it)
+    fn parse_zerocopy(builder: Builder, lit: &Lit) -> Result<Builder> {
         match lit {
             Lit::Bool(lit_bool) => {
                 if lit_bool.value() {
-                    self.zerocopy = Some("zerocopy".to_owned());
+                    Ok(builder.zerocopy("zerocopy"))
+                } else {
+                    Ok(builder)
                 }
             }
-            Lit::Str(lit_str) => {
-                self.zerocopy = Some(lit_str.value());
-            }
-            val => {
-                return Err(Error::new_spanned(
-                    val,
-                    "`zerocopy` must be a string-literal feature name",
-                ));
-            }
+            Lit::Str(lit_str) => Ok(builder.zerocopy(lit_str.value())),
+            val => Err(Error::new_spanned(
+                val,
+                "`zerocopy` must be a string-literal feature name",
+            )),
         }
-
-        Ok(())
     }
 
-    /// Parse a namevalue token pair
-    fn parse_namevalue(&mut self, manifest_dir: &str, tokens: &MetaNameValue) -> Result<()> {
-        let ident = tokens
-            .path
-            .get_ident()
-            .ok_or_else(|| syn::Error::new_spanned(tokens, "Must have specified ident"))?
-            .to_string()
-            .to_lowercase();
-        let lit = match &tokens.value {
-            Expr::Lit(ExprLit { lit, .. }) => lit,
-            expr => return Err(Error::new_spanned(expr, "Must be a literal")),
-        };
+    /// Parse a namevalue token pair into the builder
+    fn parse_namevalue(
+        builder: Builder,
+        param: &str,
+        manifest_dir: &str,
+        lit: &Lit,
+    ) -> Result<Builder> {
+        let to_error = |error: iso4217_codegen::Error| Error::new_spanned(lit, error.message());
 
-        match ident.as_str() {
-            "xml" => self.parse_xml(manifest_dir, lit),
-            "zerocopy" => self.parse_zerocopy(lit),
+        let builder = match param {
+            "xml" => builder.xml(Self::parse_path(param, manifest_dir, lit)?),
+            "json" => builder.json(Self::parse_path(param, manifest_dir, lit)?),
+            "csv" => builder.csv(Self::parse_path(param, manifest_dir, lit)?),
+            "overlay" => builder.overlay(Self::parse_path(param, manifest_dir, lit)?),
+            "idents" => builder.idents(Self::parse_path(param, manifest_dir, lit)?),
+            "naming" => builder.naming(
+                Self::parse_str(param, lit)?
+                    .parse::<Strategy>()
+                    .map_err(to_error)?,
+            ),
+            "type_name" => builder.type_name(Self::parse_str(param, lit)?),
+            "error_name" => builder.error_name(Self::parse_str(param, lit)?),
+            "vis" => builder.vis(Self::parse_str(param, lit)?),
+            "methods" => builder.methods(
+                Self::parse_str(param, lit)?
+                    .parse::<Methods>()
+                    .map_err(to_error)?,
+            ),
+            "lookup" => builder.lookup(
+                Self::parse_str(param, lit)?
+                    .parse::<Lookup>()
+                    .map_err(to_error)?,
+            ),
+            "funds" => builder.funds(Self::parse_bool(param, lit)?),
+            "x_codes" => builder.x_codes(Self::parse_bool(param, lit)?),
+            "include" => builder.include(Self::parse_list(param, lit)?),
+            "exclude" => builder.exclude(Self::parse_list(param, lit)?),
+            "countries" => builder.countries(Self::parse_list(param, lit)?),
+            "convert" => builder.convert(Self::parse_str(param, lit)?),
+            "metadata" => builder.metadata(Self::parse_bool(param, lit)?),
+            "zerocopy" => Self::parse_zerocopy(builder, lit)?,
             other => {
-                let message = format!(
-                    "{other} is not a valid paramter. The only valid parameter is `xml`, which should refer to a file relative to the calling crate's `src` directory."
-                );
-                Err(Error::new_spanned(ident, message))
+                let message = format!("{other} is not a valid paramter. {PARAMS_HELP}");
+                return Err(Error::new_spanned(lit, message));
             }
-        }
+        };
+
+        Ok(builder)
     }
 
     pub(crate) fn build(manifest_dir: &str, args: &Punctuated<Meta, Token![,]>) -> Result<Self> {
-        let mut retval = Self {
-            xml: PathBuf::default(),
-            span: Span::call_site(),
-            zerocopy: None,
-        };
+        let mut builder = Builder::new();
+        let mut spans = BTreeMap::new();
+
+        // Previously seen parameter names.
+        let mut seen = BTreeSet::new();
+
+        // Whether a data file has been given.
+        let mut data = false;
 
         for arg in args {
-            match arg {
+            let tokens = match arg {
                 Meta::Path(tokens) => {
-                    return Err(Error::new_spanned(
-                        tokens,
-                        concat!(
-                            "The only valid parameters are `xml`, which should refer to a file ",
-                            "relative to the calling crate's `src` directory, and `zerocopy`, ",
-                            "which should refer to the feature name for enabling zerocopy traits."
-                        ),
-                    ));
+                    return Err(Error::new_spanned(tokens, PARAMS_HELP));
                 }
                 Meta::List(tokens) => {
+                    return Err(Error::new_spanned(tokens, PARAMS_HELP));
+                }
+                Meta::NameValue(tokens) => tokens,
+            };
+
+            let MetaNameValue { path, value, .. } = tokens;
+            let ident = path
+                .get_ident()
+                .ok_or_else(|| Error::new_spanned(tokens, "Must have specified ident"))?;
+            let param = ident.to_string().to_lowercase();
+            let lit = match value {
+                Expr::Lit(ExprLit { lit, .. }) => lit,
+                expr => return Err(Error::new_spanned(expr, "Must be a literal")),
+            };
+
+            if !seen.insert(param.clone()) {
+                let message = format!("Multiple `{ident}` parameters.");
+                return Err(Error::new_spanned(ident, message));
+            }
+
+            if [Format::Xml, Format::Json, Format::Csv]
+                .iter()
+                .any(|format| format.param() == param)
+            {
+                if data {
                     return Err(Error::new_spanned(
-                        tokens,
-                        concat!(
-                            "The only valid parameter is `xml`, which should refer to a file ",
-                            "relative to the calling crate's `src` directory, and `zerocopy`, ",
-                            "which should refer to the feature name for enabling zerocopy traits."
-                        ),
+                        lit,
+                        "Multiple data files, only one of `xml`, `json` or `csv` may be given.",
                     ));
                 }
-                Meta::NameValue(tokens) => retval.parse_namevalue(manifest_dir, tokens)?,
+
+                data = true;
             }
+
+            builder = Self::parse_namevalue(builder, &param, manifest_dir, lit)?;
+            spans.insert(param, lit.span());
         }
 
-        Ok(retval)
+        Ok(Self { builder, spans })
     }
 }
@@ -0,0 +1,202 @@
+//! Indexed views over a currency table
+
+use crate::{CurrencyEntry, CurrencyTable};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A set of indexed views over the entries of a [`CurrencyTable`].
+///
+/// The index borrows the entries from the table it was built from, and every lookup returns the
+/// matching entries in document order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CurrencyIndex<'a> {
+    /// Entries by their 3-character currency code.
+    by_currency: BTreeMap<&'a str, Vec<&'a CurrencyEntry>>,
+
+    /// Entries by their numeric currency code.
+    by_number: BTreeMap<u16, Vec<&'a CurrencyEntry>>,
+
+    /// Entries by their (trimmed) country name.
+    by_country: BTreeMap<&'a str, Vec<&'a CurrencyEntry>>,
+
+    /// The first entry seen for each distinct numeric code, in numeric order.
+    currencies: Vec<&'a CurrencyEntry>,
+
+    /// Fund entries by the currency code of their base currency.
+    funds: BTreeMap<&'a str, Vec<&'a CurrencyEntry>>,
+}
+
+impl<'a> CurrencyIndex<'a> {
+    /// Build a new index over the entries in the given table.
+    #[must_use]
+    pub fn new(table: &'a CurrencyTable) -> Self {
+        let mut retval = Self::default();
+
+        // Previously seen numbers.
+        let mut numbers = BTreeSet::default();
+
+        for entry in table.entries() {
+            retval
+                .by_country
+                .entry(entry.country())
+                .or_default()
+                .push(entry);
+
+            if let Some(currency) = entry.currency() {
+                retval.by_currency.entry(currency).or_default().push(entry);
+            }
+
+            if let Some(number) = entry.number() {
+                retval.by_number.entry(number).or_default().push(entry);
+            }
+
+            if entry.currency().is_some()
+                && let Some(number) = entry.number()
+                && entry.name().is_some()
+                && numbers.insert(number)
+            {
+                retval.currencies.push(entry);
+            }
+        }
+
+        retval.currencies.sort_by_key(|entry| entry.number());
+
+        for entries in retval.by_country.values() {
+            // The base currency is the first non-fund currency of the country.
+            let Some(base) = entries
+                .iter()
+                .filter(|entry| !is_fund(entry))
+                .find_map(|entry| entry.currency())
+            else {
+                continue;
+            };
+
+            for &entry in entries {
+                if is_fund(entry) && entry.currency().is_some() {
+                    retval.funds.entry(base).or_default().push(entry);
+                }
+            }
+        }
+
+        retval
+    }
+
+    /// The entries using the given 3-character currency code.
+    #[must_use]
+    pub fn by_currency(&self, currency: &str) -> &[&'a CurrencyEntry] {
+        self.by_currency.get(currency).map_or(&[], Vec::as_slice)
+    }
+
+    /// The entries using the given numeric currency code.
+    #[must_use]
+    pub fn by_number(&self, number: u16) -> &[&'a CurrencyEntry] {
+        self.by_number.get(&number).map_or(&[], Vec::as_slice)
+    }
+
+    /// The entries for the given country name, as it appears in the table.
+    #[must_use]
+    pub fn by_country(&self, country: &str) -> &[&'a CurrencyEntry] {
+        self.by_country
+            .get(country.trim())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// An iterator over the distinct country names in the table, in lexical order.
+    pub fn countries(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.by_country.keys().copied()
+    }
+
+    /// The distinct currencies in the table, in numeric order.
+    ///
+    /// Only entries with a code, a number and a name are considered, and the first entry seen for
+    /// a given number is the one retained.
+    #[must_use]
+    pub fn currencies(&self) -> &[&'a CurrencyEntry] {
+        &self.currencies
+    }
+
+    /// The fund entries attached to the given base currency code (e.g. `USN` for `USD`).
+    ///
+    /// A fund is attached to the first non-fund currency used by the same country.
+    #[must_use]
+    pub fn funds(&self, base: &str) -> &[&'a CurrencyEntry] {
+        self.funds.get(base).map_or(&[], Vec::as_slice)
+    }
+
+    /// An iterator over each base currency code and the fund entries attached to it.
+    pub fn funds_by_base(&self) -> impl Iterator<Item = (&'a str, &[&'a CurrencyEntry])> + '_ {
+        self.funds
+            .iter()
+            .map(|(&base, funds)| (base, funds.as_slice()))
+    }
+}
+
+impl<'a> From<&'a CurrencyTable> for CurrencyIndex<'a> {
+    fn from(table: &'a CurrencyTable) -> Self {
+        Self::new(table)
+    }
+}
+
+/// Whether the given entry is marked as a fund.
+fn is_fund(entry: &CurrencyEntry) -> bool {
+    entry.name().is_some_and(crate::CurrencyName::is_fund)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CurrencyDoc;
+    use quick_xml::de;
+
+    const XML: &str = include_str!("2026-01-01.xml");
+
+    #[test]
+    fn lookups() {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+        let index = doc.table().index();
+
+        let euro = index.by_currency("EUR");
+        assert!(euro.len() > 30);
+        assert!(euro.iter().all(|entry| entry.number() == Some(978)));
+        assert_eq!(euro.len(), index.by_number(978).len());
+
+        let swiss = index.by_country("SWITZERLAND");
+        assert_eq!(3, swiss.len());
+
+        assert!(index.by_currency("ZZZ").is_empty());
+        assert!(index.by_country("ANTARCTICA")[0].currency().is_none());
+    }
+
+    #[test]
+    fn currencies() {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+        let index = doc.table().index();
+        let currencies = index.currencies();
+
+        assert!(currencies.is_sorted_by_key(|entry| entry.number()));
+
+        let numbers = currencies
+            .iter()
+            .filter_map(|entry| entry.number())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(numbers.len(), currencies.len());
+    }
+
+    #[yare::parameterized(
+        usd = { "USD", &["USN"] },
+        chf = { "CHF", &["CHE", "CHW"] },
+        uyu = { "UYU", &["UYI"] },
+        eur = { "EUR", &[] },
+    )]
+    fn funds(base: &str, expected: &[&str]) {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+        let index = doc.table().index();
+
+        let actual = index
+            .funds(base)
+            .iter()
+            .filter_map(|entry| entry.currency())
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, actual.as_slice());
+    }
+}
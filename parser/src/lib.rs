@@ -2,6 +2,15 @@
 
 #![doc = include_str!("../README.md")]
 
+mod index;
+#[cfg(feature = "json")]
+mod overlay;
+mod record;
+
+#[cfg(feature = "json")]
+pub use crate::overlay::{Overlay, OverlayEntry, OverlayError};
+pub use crate::{index::CurrencyIndex, record::CurrencyRecord};
+
 use chrono::{NaiveDate, ParseResult};
 use serde::{Deserialize, Serialize};
 
@@ -49,6 +58,12 @@ impl CurrencyTable {
     pub fn entries(&self) -> &[CurrencyEntry] {
         &self.entries
     }
+
+    /// Build an indexed view over the entries in this table.
+    #[must_use]
+    pub fn index(&self) -> CurrencyIndex<'_> {
+        CurrencyIndex::new(self)
+    }
 }
 
 /// An Currency XML Entry
@@ -0,0 +1,377 @@
+//! Overlays to extend or patch a currency table
+
+use crate::{CurrencyEntry, CurrencyName, CurrencyTable};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    collections::BTreeSet,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::BufRead,
+};
+
+/// An error in an overlay file, with the (1-based) line it was found on.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OverlayError {
+    /// The line of the overlay file containing the error.
+    line: usize,
+
+    /// A description of the error.
+    message: String,
+}
+
+impl OverlayError {
+    /// Create a new error for the given line.
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// The (1-based) line of the overlay file containing the error.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// A description of the error.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for OverlayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for OverlayError {}
+
+/// A single change to a currency, as read from one line of an overlay file.
+///
+/// Fields which are not given are left unchanged. A `minor_unit` of `null` marks the currency as
+/// having no minor unit.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OverlayEntry {
+    /// The 3-character currency code to add, patch or remove.
+    pub currency: String,
+
+    /// Remove every entry using this code.
+    #[serde(default)]
+    pub remove: bool,
+
+    /// The numeric currency code.
+    #[serde(default)]
+    pub number: Option<u16>,
+
+    /// The name of the currency.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The minor unit decimal places.
+    #[serde(default, deserialize_with = "present")]
+    pub minor_unit: Option<Option<u8>>,
+
+    /// Whether the currency is a fund or not.
+    #[serde(default)]
+    pub is_fund: Option<bool>,
+
+    /// The names of the countries using this currency, replacing any existing ones.
+    #[serde(default)]
+    pub countries: Option<Vec<String>>,
+}
+
+/// Deserialize a field which may be explicitly `null`, as distinct from missing.
+#[allow(clippy::option_option)]
+fn present<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Option<u8>>, D::Error> {
+    Option::<u8>::deserialize(deserializer).map(Some)
+}
+
+/// A set of changes to apply to a [`CurrencyTable`].
+///
+/// Overlays are written as JSON lines, with one [`OverlayEntry`] per line, and blank lines or
+/// lines starting with `#` ignored:
+///
+/// ```text
+/// # Offshore renminbi, which has no ISO 4217 code.
+/// {"currency": "CNH", "number": 0, "name": "Yuan Renminbi (Offshore)", "minor_unit": 2}
+/// {"currency": "VED", "name": "Bolívar Digital"}
+/// {"currency": "XTS", "remove": true}
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Overlay {
+    /// The entries of this overlay, with the line they were read from.
+    entries: Vec<(usize, OverlayEntry)>,
+}
+
+impl Overlay {
+    /// Read an overlay from JSON lines.
+    ///
+    /// # Errors
+    ///
+    /// - [`OverlayError`] when a line cannot be read, is not a valid [`OverlayEntry`], or repeats a
+    ///   currency code given on an earlier line.
+    pub fn from_json_lines<R: BufRead>(reader: R) -> Result<Self, OverlayError> {
+        let mut retval = Self::default();
+        let mut seen = BTreeSet::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|error| OverlayError::new(line_number, error.to_string()))?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = serde_json::from_str::<OverlayEntry>(line)
+                .map_err(|error| OverlayError::new(line_number, error.to_string()))?;
+
+            validate(line_number, &entry)?;
+
+            if !seen.insert(entry.currency.clone()) {
+                return Err(OverlayError::new(
+                    line_number,
+                    format!("`{}` is given more than once", entry.currency),
+                ));
+            }
+
+            retval.entries.push((line_number, entry));
+        }
+
+        Ok(retval)
+    }
+
+    /// The entries in this overlay, with the line they were read from.
+    #[must_use]
+    pub fn entries(&self) -> &[(usize, OverlayEntry)] {
+        &self.entries
+    }
+}
+
+/// Check a single overlay entry for problems which don't depend on the table.
+fn validate(line: usize, entry: &OverlayEntry) -> Result<(), OverlayError> {
+    let currency = &entry.currency;
+
+    if currency.len() != 3 || !currency.bytes().all(|byte| byte.is_ascii_uppercase()) {
+        return Err(OverlayError::new(
+            line,
+            format!("`{currency}` is not a 3-letter uppercase code"),
+        ));
+    }
+
+    if entry.number.is_some_and(|number| number > 999) {
+        return Err(OverlayError::new(
+            line,
+            format!("{currency} has a numeric code with more than 3 digits"),
+        ));
+    }
+
+    if entry.remove
+        && (entry.number.is_some()
+            || entry.name.is_some()
+            || entry.minor_unit.is_some()
+            || entry.is_fund.is_some()
+            || entry.countries.is_some())
+    {
+        return Err(OverlayError::new(
+            line,
+            format!("{currency} is removed, but other fields are also given"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Apply the changes in an overlay entry to an existing table entry.
+fn patch(entry: &mut CurrencyEntry, overlay: &OverlayEntry) {
+    if let Some(number) = overlay.number {
+        entry.number = Some(number);
+    }
+
+    if let Some(minor_unit) = overlay.minor_unit {
+        entry.minor_unit = Some(minor_unit.map_or_else(|| "N.A.".to_owned(), |u| u.to_string()));
+    }
+
+    if overlay.name.is_some() || overlay.is_fund.is_some() {
+        let name = entry.name.get_or_insert_with(|| CurrencyName {
+            is_fund: None,
+            name: String::new(),
+        });
+
+        if let Some(value) = &overlay.name {
+            value.clone_into(&mut name.name);
+        }
+
+        if let Some(is_fund) = overlay.is_fund {
+            name.is_fund = Some(is_fund);
+        }
+    }
+}
+
+impl CurrencyTable {
+    /// Apply the given overlay to this table.
+    ///
+    /// Codes already in the table have the given fields patched on every entry using them (or are
+    /// removed), and new codes are added with one entry per country, or a single entry without a
+    /// country. If `countries` is given, the existing entries for a code are replaced.
+    ///
+    /// # Errors
+    ///
+    /// - [`OverlayError`] when a removed code is not in the table, a new code is missing its
+    ///   number or name, or a numeric code would be shared by two currency codes.
+    pub fn apply(&mut self, overlay: &Overlay) -> Result<(), OverlayError> {
+        for (line, change) in overlay.entries() {
+            let line = *line;
+            let currency = change.currency.as_str();
+            let existing = self
+                .entries
+                .iter()
+                .position(|entry| entry.currency() == Some(currency));
+
+            if change.remove {
+                if existing.is_none() {
+                    return Err(OverlayError::new(
+                        line,
+                        format!("{currency} cannot be removed, it is not in the table"),
+                    ));
+                }
+
+                self.entries
+                    .retain(|entry| entry.currency() != Some(currency));
+                continue;
+            }
+
+            let mut template = if let Some(position) = existing {
+                self.entries[position].clone()
+            } else {
+                if change.number.is_none() || change.name.is_none() {
+                    return Err(OverlayError::new(
+                        line,
+                        format!(
+                            "{currency} is not in the table, so `number` and `name` are required"
+                        ),
+                    ));
+                }
+
+                CurrencyEntry {
+                    country: String::new(),
+                    name: None,
+                    currency: Some(currency.to_owned()),
+                    number: None,
+                    minor_unit: None,
+                }
+            };
+
+            patch(&mut template, change);
+
+            if let Some(number) = template.number
+                && let Some(other) = self.entries.iter().find(|entry| {
+                    entry.number() == Some(number)
+                        && entry.currency().is_some_and(|other| other != currency)
+                })
+            {
+                return Err(OverlayError::new(
+                    line,
+                    format!(
+                        "{currency} cannot use {number:03}, it is already used by {}",
+                        other.currency().unwrap_or_default()
+                    ),
+                ));
+            }
+
+            match (&change.countries, existing) {
+                (Some(countries), _) => {
+                    self.entries
+                        .retain(|entry| entry.currency() != Some(currency));
+                    for country in countries {
+                        let mut entry = template.clone();
+                        country.trim().clone_into(&mut entry.country);
+                        self.entries.push(entry);
+                    }
+                }
+                (None, Some(_)) => {
+                    for entry in &mut self.entries {
+                        if entry.currency() == Some(currency) {
+                            patch(entry, change);
+                        }
+                    }
+                }
+                (None, None) => self.entries.push(template),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CurrencyDoc;
+    use quick_xml::de;
+
+    const XML: &str = include_str!("2026-01-01.xml");
+
+    fn apply(overlay: &str) -> Result<CurrencyTable, OverlayError> {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+        let mut table = doc.table().clone();
+        let overlay = Overlay::from_json_lines(overlay.as_bytes())?;
+        table.apply(&overlay)?;
+        Ok(table)
+    }
+
+    #[test]
+    fn extend_and_patch() {
+        let table = apply(concat!(
+            "# Offshore renminbi\n",
+            r#"{"currency": "CNH", "number": 0, "name": "Yuan Renminbi (Offshore)", "minor_unit": 2}"#,
+            "\n\n",
+            r#"{"currency": "VED", "name": "Bolívar Digital"}"#,
+            "\n",
+            r#"{"currency": "CLF", "minor_unit": null}"#,
+            "\n",
+            r#"{"currency": "XTS", "remove": true}"#,
+            "\n",
+            r#"{"currency": "CHF", "countries": ["SWITZERLAND"]}"#,
+        ))
+        .expect("overlay");
+        let index = table.index();
+
+        let cnh = index.by_currency("CNH");
+        assert_eq!(1, cnh.len());
+        assert_eq!(Some(0), cnh[0].number());
+        assert_eq!(Some(2), cnh[0].minor_unit());
+        assert_eq!("", cnh[0].country());
+
+        let ved = index.by_currency("VED")[0];
+        assert_eq!("Bolívar Digital", ved.name().expect("name").name());
+        assert_eq!(Some(2), ved.minor_unit());
+
+        assert_eq!(None, index.by_currency("CLF")[0].minor_unit());
+        assert!(index.by_currency("CLF")[0].name().expect("name").is_fund());
+        assert!(index.by_currency("XTS").is_empty());
+
+        let chf = index.by_currency("CHF");
+        assert_eq!(1, chf.len());
+        assert_eq!("SWITZERLAND", chf[0].country());
+    }
+
+    #[yare::parameterized(
+        syntax = { "\n{\"currency\": \"CNH\"", 2 },
+        unknown_field = { r#"{"currency": "CNH", "numbr": 1}"#, 1 },
+        lowercase = { r#"{"currency": "cnh"}"#, 1 },
+        duplicate = { "{\"currency\": \"VED\", \"name\": \"A\"}\n{\"currency\": \"VED\", \"name\": \"B\"}", 2 },
+        remove_and_patch = { r#"{"currency": "XTS", "remove": true, "name": "Test"}"#, 1 },
+        remove_missing = { r#"{"currency": "CNH", "remove": true}"#, 1 },
+        missing_fields = { "# comment\n{\"currency\": \"CNH\", \"name\": \"Offshore\"}", 2 },
+        number_collision = { r#"{"currency": "CNH", "number": 156, "name": "Offshore"}"#, 1 },
+    )]
+    fn errors(overlay: &str, line: usize) {
+        let error = apply(overlay).expect_err("error");
+        assert_eq!(line, error.line(), "{error}");
+    }
+}
@@ -0,0 +1,241 @@
+//! Normalized currency records
+
+use crate::{CurrencyEntry, CurrencyName, CurrencyTable};
+use serde::{Deserialize, Serialize};
+
+/// A normalized, flat representation of a single currency entry.
+///
+/// This is the schema used when exporting a [`CurrencyTable`] to CSV or JSON lines: country names
+/// are trimmed, the minor unit is a number (or missing), and the numeric code is written as a
+/// zero-padded, three-digit string (e.g. `"008"`).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct CurrencyRecord {
+    /// The name of the country.
+    pub country: String,
+
+    /// The name of the currency.
+    pub name: Option<String>,
+
+    /// The 3-character currency code.
+    pub currency: Option<String>,
+
+    /// The numeric currency code.
+    #[serde(default, with = "padded")]
+    pub number: Option<u16>,
+
+    /// The minor unit decimal places.
+    pub minor_unit: Option<u8>,
+
+    /// Whether the currency is a fund or not.
+    #[serde(default)]
+    pub is_fund: bool,
+}
+
+impl From<&CurrencyEntry> for CurrencyRecord {
+    fn from(entry: &CurrencyEntry) -> Self {
+        Self {
+            country: entry.country().to_owned(),
+            name: entry.name().map(|name| name.name().to_owned()),
+            currency: entry.currency().map(str::to_owned),
+            number: entry.number(),
+            minor_unit: entry.minor_unit(),
+            is_fund: entry.name().is_some_and(CurrencyName::is_fund),
+        }
+    }
+}
+
+impl From<CurrencyRecord> for CurrencyEntry {
+    fn from(record: CurrencyRecord) -> Self {
+        let name = match (record.name, record.is_fund) {
+            (Some(name), is_fund) => Some(CurrencyName {
+                is_fund: is_fund.then_some(true),
+                name,
+            }),
+            (None, true) => Some(CurrencyName {
+                is_fund: Some(true),
+                name: String::new(),
+            }),
+            (None, false) => None,
+        };
+
+        Self {
+            country: record.country,
+            name,
+            currency: record.currency,
+            number: record.number,
+            minor_unit: record.minor_unit.map(|unit| unit.to_string()),
+        }
+    }
+}
+
+impl FromIterator<CurrencyRecord> for CurrencyTable {
+    fn from_iter<T: IntoIterator<Item = CurrencyRecord>>(iter: T) -> Self {
+        Self {
+            entries: iter.into_iter().map(CurrencyEntry::from).collect(),
+        }
+    }
+}
+
+impl CurrencyTable {
+    /// An iterator over the normalized records of the entries in this table.
+    pub fn records(&self) -> impl Iterator<Item = CurrencyRecord> + '_ {
+        self.entries.iter().map(CurrencyRecord::from)
+    }
+
+    /// Write the entries in this table as CSV, with a header row.
+    ///
+    /// # Errors
+    ///
+    /// - [`csv::Error`] when the records could not be written.
+    #[cfg(feature = "csv")]
+    pub fn write_csv<W: std::io::Write>(&self, writer: W) -> csv::Result<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+
+        for record in self.records() {
+            writer.serialize(record)?;
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Write the entries in this table as JSON lines, one record per line.
+    ///
+    /// # Errors
+    ///
+    /// - [`serde_json::Error`] when the records could not be written.
+    #[cfg(feature = "json")]
+    pub fn write_json_lines<W: std::io::Write>(&self, mut writer: W) -> serde_json::Result<()> {
+        for record in self.records() {
+            serde_json::to_writer(&mut writer, &record)?;
+            writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+        }
+
+        writer.flush().map_err(serde_json::Error::io)
+    }
+
+    /// Read a table from CSV with a header row, as written by [`write_csv()`](Self::write_csv).
+    ///
+    /// # Errors
+    ///
+    /// - [`csv::Error`] when a row could not be read or does not match the record schema.
+    #[cfg(feature = "csv")]
+    pub fn read_csv<R: std::io::Read>(reader: R) -> csv::Result<Self> {
+        csv::Reader::from_reader(reader)
+            .into_deserialize::<CurrencyRecord>()
+            .collect()
+    }
+
+    /// Read a table from JSON lines, as written by [`write_json_lines()`](Self::write_json_lines).
+    ///
+    /// Blank lines are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`serde_json::Error`] when a line could not be read or does not match the record schema.
+    #[cfg(feature = "json")]
+    pub fn read_json_lines<R: std::io::BufRead>(reader: R) -> serde_json::Result<Self> {
+        reader
+            .lines()
+            .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+            .map(|line| {
+                line.map_err(serde_json::Error::io)
+                    .and_then(|line| serde_json::from_str::<CurrencyRecord>(&line))
+            })
+            .collect()
+    }
+}
+
+/// Serialize/Deserialize an optional numeric code as a zero-padded string.
+mod padded {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    // The signature is dictated by `#[serde(with)]`.
+    #[allow(clippy::ref_option, clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<u16>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(number) => serializer.serialize_str(&format!("{number:03}")),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u16>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse::<u16>().map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::CurrencyDoc;
+    use quick_xml::de;
+
+    const XML: &str = include_str!("2026-01-01.xml");
+
+    #[test]
+    fn normalized() {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+        let records = doc.table().records().collect::<Vec<_>>();
+
+        assert_eq!(doc.table().entries().len(), records.len());
+
+        let lek = records
+            .iter()
+            .find(|record| record.currency.as_deref() == Some("ALL"))
+            .expect("ALL");
+        assert_eq!("ALBANIA", lek.country);
+        assert_eq!(Some(8), lek.number);
+        assert_eq!(Some(2), lek.minor_unit);
+        assert!(!lek.is_fund);
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn csv() {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+
+        let mut output = Vec::new();
+        doc.table().write_csv(&mut output).expect("csv");
+        let output = String::from_utf8(output).expect("utf8");
+
+        let mut lines = output.lines();
+        assert_eq!(
+            Some("country,name,currency,number,minor_unit,is_fund"),
+            lines.next()
+        );
+        assert!(output.contains("\nALBANIA,Lek,ALL,008,2,false\n"));
+        assert!(output.contains("\nANTARCTICA,No universal currency,,,,false\n"));
+        assert_eq!(doc.table().entries().len(), lines.count());
+
+        let table = crate::CurrencyTable::read_csv(output.as_bytes()).expect("read");
+        assert!(table.records().eq(doc.table().records()));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_lines() {
+        let doc = de::from_str::<CurrencyDoc>(XML).expect("XML");
+
+        let mut output = Vec::new();
+        doc.table().write_json_lines(&mut output).expect("json");
+        let output = String::from_utf8(output).expect("utf8");
+
+        assert_eq!(doc.table().entries().len(), output.lines().count());
+        assert!(output.contains(
+            r#"{"country":"ALBANIA","name":"Lek","currency":"ALL","number":"008","minor_unit":2,"is_fund":false}"#
+        ));
+
+        let table = crate::CurrencyTable::read_json_lines(output.as_bytes()).expect("read");
+        assert!(table.records().eq(doc.table().records()));
+    }
+}
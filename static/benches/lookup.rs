@@ -0,0 +1,78 @@
+//! Lookup latency of the `match` and `table` generated code.
+//!
+//! Run with `cargo bench -p iso4217-static`.
+
+use std::{hint::black_box, time::Instant};
+
+#[allow(dead_code)]
+mod matched {
+    iso4217_macros::generate!(
+        xml = "list-one.xml",
+        idents = "idents.json",
+        vis = "pub(crate)",
+        methods = "numeric, string, name, fund, minor_unit, index",
+        lookup = "match"
+    );
+}
+
+#[allow(dead_code)]
+mod table {
+    iso4217_macros::generate!(
+        xml = "list-one.xml",
+        idents = "idents.json",
+        vis = "pub(crate)",
+        methods = "numeric, string, name, fund, minor_unit, index",
+        lookup = "table"
+    );
+}
+
+/// The number of times each lookup is repeated.
+const ITERATIONS: u32 = 10_000;
+
+/// Time the given lookup over every input, returning the mean nanoseconds per lookup.
+fn time<T: Copy, R>(inputs: &[T], lookup: impl Fn(T) -> R) -> f64 {
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        for &input in inputs {
+            black_box(lookup(black_box(input)));
+        }
+    }
+
+    let lookups = f64::from(ITERATIONS) * f64::from(u32::try_from(inputs.len()).expect("len"));
+    start.elapsed().as_secs_f64() * 1e9 / lookups
+}
+
+/// Time a lookup in both modes, and print the results.
+macro_rules! compare {
+    ($label:literal, $inputs:expr, | $var:ident | $lookup:expr) => {{
+        let matched = {
+            use matched::Currency;
+            time($inputs, |$var| $lookup)
+        };
+        let table = {
+            use table::Currency;
+            time($inputs, |$var| $lookup)
+        };
+
+        println!("{:<16} {matched:>8.2} ns {table:>8.2} ns", $label);
+    }};
+}
+
+fn main() {
+    let numbers = (0..=999).collect::<Vec<u16>>();
+    let codes = matched::Currency::ALL.map(|currency| currency.as_str());
+    let indices = (0..matched::Currency::COUNT).collect::<Vec<_>>();
+
+    println!("{:<16} {:>11} {:>11}", "lookup", "match", "table");
+    compare!("from_u16", &numbers, |number| Currency::from_u16(number));
+    compare!("from_str_slice", &codes, |code| Currency::from_str_slice(
+        code
+    ));
+    compare!("as_str", &indices, |index| Currency::ALL[index].as_str());
+    compare!("name", &indices, |index| Currency::ALL[index].name());
+    compare!("is_fund", &indices, |index| Currency::ALL[index].is_fund());
+    compare!("minor_unit", &indices, |index| Currency::ALL[index]
+        .minor_unit());
+    compare!("index", &indices, |index| Currency::ALL[index].index());
+}
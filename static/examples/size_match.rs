@@ -0,0 +1,42 @@
+//! Every lookup of the `match` generated code, to compare release binary sizes with
+//! `size_table`.
+//!
+//! Run with `cargo run --release -p iso4217-static --example size_match -- USD 840`.
+
+use std::{env, hint::black_box};
+
+#[allow(dead_code)]
+mod generated {
+    iso4217_macros::generate!(
+        xml = "list-one.xml",
+        idents = "idents.json",
+        vis = "pub(crate)",
+        methods = "numeric, string, name, fund, minor_unit, index",
+        lookup = "match"
+    );
+}
+
+use generated::Currency;
+
+fn main() {
+    for arg in env::args().skip(1) {
+        let currency = arg
+            .parse()
+            .map_or_else(|_error| Currency::from_str_slice(&arg), Currency::from_u16);
+
+        if let Ok(currency) = black_box(currency) {
+            println!(
+                "{} {:03} {} fund={} minor_unit={:?} index={}",
+                currency.as_str(),
+                currency as u16,
+                currency.name(),
+                currency.is_fund(),
+                currency.minor_unit(),
+                currency.index(),
+            );
+            black_box(Currency::from_index(currency.index()));
+        } else {
+            println!("{arg}: not a currency");
+        }
+    }
+}
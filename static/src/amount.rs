@@ -0,0 +1,380 @@
+//! Exact decimal amounts
+
+use crate::{Currency, Error};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// Errors encountered when interacting with amounts in a currency.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum AmountError {
+    /// The amount given is not a decimal number in the expected format.
+    InvalidFormat,
+    /// The amount given is too large to be represented.
+    Overflow,
+    /// The amount given is outside the range the format allows.
+    OutOfRange,
+    /// The amount given has more fraction digits than the currency or format allows.
+    TooManyFractionDigits,
+    /// The currency given is not valid, or not allowed by the format.
+    InvalidCurrency(Error),
+    /// The date given is not a valid date, or not allowed by the format.
+    InvalidDate,
+}
+
+impl Display for AmountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::InvalidFormat => f.write_str("the amount is not a decimal number"),
+            Self::Overflow => f.write_str("the amount is too large"),
+            Self::OutOfRange => f.write_str("the amount is outside the range the format allows"),
+            Self::TooManyFractionDigits => f.write_str(
+                "the amount has more fraction digits than the currency or format allows",
+            ),
+            Self::InvalidCurrency(_) => f.write_str("the currency is not valid or not allowed"),
+            Self::InvalidDate => f.write_str("the date is not valid"),
+        }
+    }
+}
+
+impl core::error::Error for AmountError {}
+
+impl From<Error> for AmountError {
+    fn from(value: Error) -> Self {
+        Self::InvalidCurrency(value)
+    }
+}
+
+/// An exact decimal amount, `value × 10^-scale`.
+///
+/// Amounts are compared by value, so `1.5` and `1.50` are equal, but keep the scale they were
+/// created with when displayed:
+///
+/// ```rust
+/// use iso4217_static::{Amount, Currency};
+///
+/// let amount = Amount::from_str_slice("-123.450").expect("amount");
+/// assert_eq!(Amount::new(-12_345, 2), amount);
+/// assert_eq!("-123.450", amount.to_string());
+/// assert_eq!(2, amount.fraction_digits());
+/// assert!(amount.fits_minor_unit(Currency::Euro));
+/// assert!(!amount.fits_minor_unit(Currency::Yen));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Amount {
+    /// The unscaled value.
+    value: i128,
+    /// The number of fraction digits.
+    scale: u8,
+}
+
+impl Amount {
+    /// Create a new amount of `value × 10^-scale`.
+    #[must_use]
+    pub const fn new(value: i128, scale: u8) -> Self {
+        Self { value, scale }
+    }
+
+    /// Parse an amount with a `.` decimal separator, e.g. `-123.45`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::InvalidFormat`] when the string is not a decimal number.
+    /// - [`AmountError::Overflow`] when the number has too many digits to represent.
+    pub const fn from_str_slice(value: &str) -> Result<Self, AmountError> {
+        Self::parse(value.as_bytes(), b'.')
+    }
+
+    /// Parse an optionally signed amount with the given decimal separator.
+    pub(crate) const fn parse(bytes: &[u8], separator: u8) -> Result<Self, AmountError> {
+        let (negative, mut i) = match bytes {
+            [b'-', ..] => (true, 1),
+            [b'+', ..] => (false, 1),
+            _ => (false, 0),
+        };
+
+        let mut value: i128 = 0;
+        let mut scale: u8 = 0;
+        let mut digits = 0;
+        let mut fraction = false;
+
+        while i < bytes.len() {
+            let byte = bytes[i];
+            i += 1;
+
+            if byte == separator && !fraction {
+                fraction = true;
+                continue;
+            }
+
+            if !byte.is_ascii_digit() {
+                return Err(AmountError::InvalidFormat);
+            }
+
+            value = match value.checked_mul(10) {
+                Some(value) => match value.checked_add((byte - b'0') as i128) {
+                    Some(value) => value,
+                    None => return Err(AmountError::Overflow),
+                },
+                None => return Err(AmountError::Overflow),
+            };
+            digits += 1;
+
+            if fraction {
+                scale = match scale.checked_add(1) {
+                    Some(scale) => scale,
+                    None => return Err(AmountError::Overflow),
+                };
+            }
+        }
+
+        if digits == 0 {
+            return Err(AmountError::InvalidFormat);
+        }
+
+        Ok(Self {
+            value: if negative { -value } else { value },
+            scale,
+        })
+    }
+
+    /// The unscaled value of this amount.
+    #[must_use]
+    pub const fn value(self) -> i128 {
+        self.value
+    }
+
+    /// The number of fraction digits this amount was created with.
+    #[must_use]
+    pub const fn scale(self) -> u8 {
+        self.scale
+    }
+
+    /// Whether this amount is less than zero.
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.value < 0
+    }
+
+    /// This amount with trailing fraction zeros removed.
+    #[must_use]
+    pub const fn normalize(self) -> Self {
+        let mut retval = self;
+
+        while retval.scale > 0 && retval.value % 10 == 0 {
+            retval.value /= 10;
+            retval.scale -= 1;
+        }
+
+        retval
+    }
+
+    /// The number of significant fraction digits, ignoring trailing zeros.
+    #[must_use]
+    pub const fn fraction_digits(self) -> u8 {
+        self.normalize().scale
+    }
+
+    /// The number of significant digits, ignoring leading zeros and trailing fraction zeros.
+    #[must_use]
+    pub const fn total_digits(self) -> u8 {
+        let mut value = self.normalize().value.unsigned_abs();
+        let mut retval = 1;
+
+        while value >= 10 {
+            value /= 10;
+            retval += 1;
+        }
+
+        retval
+    }
+
+    /// Whether this amount can be expressed in the minor unit of the given currency.
+    ///
+    /// Currencies without a minor unit, such as precious metals, accept any amount.
+    #[must_use]
+    pub const fn fits_minor_unit(self, currency: Currency) -> bool {
+        match currency.minor_unit() {
+            Some(minor_unit) => self.fraction_digits() <= minor_unit,
+            None => true,
+        }
+    }
+
+    /// This amount with the given scale, if it can be represented exactly.
+    #[must_use]
+    pub const fn rescale(self, scale: u8) -> Option<Self> {
+        let mut retval = self;
+
+        while retval.scale < scale {
+            retval.value = match retval.value.checked_mul(10) {
+                Some(value) => value,
+                None => return None,
+            };
+            retval.scale += 1;
+        }
+
+        while retval.scale > scale {
+            if retval.value % 10 != 0 {
+                return None;
+            }
+
+            retval.value /= 10;
+            retval.scale -= 1;
+        }
+
+        Some(retval)
+    }
+
+    /// Write this amount with the given decimal separator.
+    pub(crate) fn write(&self, f: &mut impl Write, separator: char) -> FmtResult {
+        let mut digits = [b'0'; 39];
+        let mut start = digits.len();
+        let mut value = self.value.unsigned_abs();
+
+        loop {
+            start -= 1;
+            // A single decimal digit always fits in a byte.
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = (value % 10) as u8;
+            digits[start] = b'0' + digit;
+            value /= 10;
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        if self.is_negative() {
+            f.write_char('-')?;
+        }
+
+        let digits = &digits[start..];
+        let scale = usize::from(self.scale);
+
+        if digits.len() > scale {
+            let (int, fraction) = digits.split_at(digits.len() - scale);
+            int.iter().try_for_each(|&b| f.write_char(char::from(b)))?;
+
+            if scale > 0 {
+                f.write_char(separator)?;
+                fraction
+                    .iter()
+                    .try_for_each(|&b| f.write_char(char::from(b)))?;
+            }
+        } else {
+            f.write_char('0')?;
+            f.write_char(separator)?;
+            (digits.len()..scale).try_for_each(|_| f.write_char('0'))?;
+            digits
+                .iter()
+                .try_for_each(|&b| f.write_char(char::from(b)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.write(f, '.')
+    }
+}
+
+impl FromStr for Amount {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_slice(s)
+    }
+}
+
+impl PartialEq for Amount {
+    fn eq(&self, other: &Self) -> bool {
+        let (this, other) = (self.normalize(), other.normalize());
+        this.value == other.value && this.scale == other.scale
+    }
+}
+
+impl Eq for Amount {}
+
+impl Hash for Amount {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.value.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::buf::Buf;
+
+    #[test]
+    fn parse() {
+        const CASES: &[(&str, Result<Amount, AmountError>, &str)] = &[
+            ("123.45", Ok(Amount::new(12_345, 2)), "123.45"),
+            ("-0.05", Ok(Amount::new(-5, 2)), "-0.05"),
+            ("+7", Ok(Amount::new(7, 0)), "7"),
+            ("100.", Ok(Amount::new(100, 0)), "100"),
+            (".5", Ok(Amount::new(5, 1)), "0.5"),
+            ("0.000", Ok(Amount::new(0, 3)), "0.000"),
+            ("", Err(AmountError::InvalidFormat), ""),
+            ("-", Err(AmountError::InvalidFormat), ""),
+            ("1.2.3", Err(AmountError::InvalidFormat), ""),
+            ("1,23", Err(AmountError::InvalidFormat), ""),
+            (
+                "1000000000000000000000000000000000000000",
+                Err(AmountError::Overflow),
+                "",
+            ),
+        ];
+
+        for &(value, expected, display) in CASES {
+            let actual = Amount::from_str_slice(value);
+            assert_eq!(expected, actual, "{value:?}");
+
+            if let Ok(amount) = actual {
+                let mut buf = Buf::new();
+                write!(buf, "{amount}").expect("display");
+                assert_eq!(display.as_bytes(), buf.as_bytes(), "{value:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn error_display() {
+        const CASES: &[(AmountError, &str)] = &[
+            (AmountError::Overflow, "the amount is too large"),
+            (
+                AmountError::InvalidCurrency(Error::InvalidCode),
+                "the currency is not valid or not allowed",
+            ),
+        ];
+
+        for &(error, expected) in CASES {
+            let mut buf = Buf::new();
+            write!(buf, "{error}").expect("display");
+            assert_eq!(expected.as_bytes(), buf.as_bytes(), "{error:?}");
+        }
+    }
+
+    #[test]
+    fn digits() {
+        let amount = Amount::new(1_234_500, 4);
+
+        assert_eq!(Amount::new(1_2345, 2), amount.normalize());
+        assert_eq!(2, amount.fraction_digits());
+        assert_eq!(5, amount.total_digits());
+        assert_eq!(1, Amount::new(0, 3).total_digits());
+        assert_eq!(Some(Amount::new(12_345, 2)), amount.rescale(2));
+        assert_eq!(None, amount.rescale(1));
+        assert_eq!(2, amount.rescale(5).map_or(0, Amount::fraction_digits));
+        assert!(amount.fits_minor_unit(Currency::UsDollar));
+        assert!(!amount.fits_minor_unit(Currency::Yen));
+        assert!(amount.fits_minor_unit(Currency::Gold));
+    }
+}
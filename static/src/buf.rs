@@ -0,0 +1,36 @@
+//! A fixed-size buffer to display values into in tests, since this crate has no `alloc`.
+
+use core::fmt::{Result as FmtResult, Write};
+
+/// A fixed-size buffer to display values into.
+pub(crate) struct Buf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buf {
+    /// Create a new, empty buffer.
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    /// Retrieve the bytes written so far.
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
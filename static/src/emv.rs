@@ -0,0 +1,296 @@
+//! EMV and ISO 8583 encodings
+//!
+//! Card payment messages carry the numeric code of a currency, either as two bytes of packed
+//! binary-coded decimal (BCD), as in the EMV tags `5F2A` and `9F51` and binary ISO 8583 fields
+//! 49–51, or as three ASCII digits, as in the `n3` ISO 8583 fields 49–51:
+//!
+//! ```rust
+//! use iso4217_static::{Currency, emv};
+//!
+//! assert_eq!([0x08, 0x40], Currency::UsDollar.to_bcd());
+//! assert_eq!(Ok(Currency::UsDollar), Currency::from_bcd([0x08, 0x40]));
+//! assert_eq!(*b"840", Currency::UsDollar.to_n3());
+//!
+//! let tlv = emv::transaction_currency_code(Currency::UsDollar);
+//! assert_eq!([0x5f, 0x2a, 0x02, 0x08, 0x40], tlv);
+//! assert_eq!(
+//!     Ok((Currency::UsDollar, &[][..])),
+//!     emv::read_transaction_currency_code(&tlv)
+//! );
+//! ```
+
+use crate::{Currency, Error};
+
+/// The EMV tag of the Transaction Currency Code, `n3` in two bytes of BCD.
+pub const TRANSACTION_CURRENCY_CODE: [u8; 2] = [0x5f, 0x2a];
+
+/// The EMV tag of the Transaction Currency Exponent, `n1` in one byte of BCD.
+pub const TRANSACTION_CURRENCY_EXPONENT: [u8; 2] = [0x5f, 0x36];
+
+/// The EMV tag of the Application Currency Code, `n3` in two bytes of BCD.
+pub const APPLICATION_CURRENCY_CODE: [u8; 2] = [0x9f, 0x51];
+
+/// The value of a BCD digit, if the nibble is one.
+///
+/// BCD is binary, not text, so a nibble above nine is a malformed code rather than a bad
+/// character.
+const fn digit(nibble: u8) -> Result<u16, Error> {
+    if nibble > 9 {
+        Err(Error::InvalidCode)
+    } else {
+        Ok(nibble as u16)
+    }
+}
+
+/// The TLV of the given currency code tag, with its BCD value.
+const fn currency_code_tlv(tag: [u8; 2], currency: Currency) -> [u8; 5] {
+    let [high, low] = currency.to_bcd();
+    [tag[0], tag[1], 2, high, low]
+}
+
+/// Read the TLV of the given currency code tag from the start of the bytes, returning the
+/// currency and the bytes after it.
+const fn read_currency_code_tlv(tag: [u8; 2], bytes: &[u8]) -> Result<(Currency, &[u8]), Error> {
+    match bytes {
+        [a, b, 2, high, low, rest @ ..] if *a == tag[0] && *b == tag[1] => {
+            match Currency::from_bcd([*high, *low]) {
+                Ok(currency) => Ok((currency, rest)),
+                Err(error) => Err(error),
+            }
+        }
+        [a, b, _, ..] if *a == tag[0] && *b == tag[1] => Err(Error::InvalidLength),
+        [_, _, _, ..] => Err(Error::InvalidCode),
+        _ => Err(Error::InvalidLength),
+    }
+}
+
+/// The Transaction Currency Code TLV (`5F2A`) of the given currency.
+#[must_use]
+pub const fn transaction_currency_code(currency: Currency) -> [u8; 5] {
+    currency_code_tlv(TRANSACTION_CURRENCY_CODE, currency)
+}
+
+/// The Application Currency Code TLV (`9F51`) of the given currency.
+#[must_use]
+pub const fn application_currency_code(currency: Currency) -> [u8; 5] {
+    currency_code_tlv(APPLICATION_CURRENCY_CODE, currency)
+}
+
+/// The Transaction Currency Exponent TLV (`5F36`) of the given currency, which is its minor
+/// unit, if it has one.
+///
+/// ```rust
+/// use iso4217_static::{Currency, emv};
+///
+/// assert_eq!(
+///     Some([0x5f, 0x36, 0x01, 0x02]),
+///     emv::transaction_currency_exponent(Currency::UsDollar)
+/// );
+/// assert_eq!(None, emv::transaction_currency_exponent(Currency::Gold));
+/// ```
+#[must_use]
+pub const fn transaction_currency_exponent(currency: Currency) -> Option<[u8; 4]> {
+    match currency.minor_unit() {
+        Some(exponent) if exponent <= 9 => Some([
+            TRANSACTION_CURRENCY_EXPONENT[0],
+            TRANSACTION_CURRENCY_EXPONENT[1],
+            1,
+            exponent,
+        ]),
+        _ => None,
+    }
+}
+
+/// Read a Transaction Currency Code TLV (`5F2A`) from the start of the bytes, returning the
+/// currency and the bytes after it.
+///
+/// # Errors
+///
+/// - [`Error::InvalidLength`] when the bytes are too short, or the TLV length is not two.
+/// - [`Error::InvalidCode`] when the tag is not `5F2A`, the value is not BCD, or the value is not
+///   a currency.
+pub const fn read_transaction_currency_code(bytes: &[u8]) -> Result<(Currency, &[u8]), Error> {
+    read_currency_code_tlv(TRANSACTION_CURRENCY_CODE, bytes)
+}
+
+/// Read an Application Currency Code TLV (`9F51`) from the start of the bytes, returning the
+/// currency and the bytes after it.
+///
+/// # Errors
+///
+/// - [`Error`] when the TLV is not a currency code, see [`read_transaction_currency_code()`].
+pub const fn read_application_currency_code(bytes: &[u8]) -> Result<(Currency, &[u8]), Error> {
+    read_currency_code_tlv(APPLICATION_CURRENCY_CODE, bytes)
+}
+
+/// Read a Transaction Currency Exponent TLV (`5F36`) from the start of the bytes, returning the
+/// exponent and the bytes after it.
+///
+/// # Errors
+///
+/// - [`Error::InvalidLength`] when the bytes are too short, or the TLV length is not one.
+/// - [`Error::InvalidCode`] when the tag is not `5F36`, or the value is not a BCD digit.
+pub const fn read_transaction_currency_exponent(bytes: &[u8]) -> Result<(u8, &[u8]), Error> {
+    match bytes {
+        [0x5f, 0x36, 1, exponent, rest @ ..] => {
+            if *exponent > 9 {
+                Err(Error::InvalidCode)
+            } else {
+                Ok((*exponent, rest))
+            }
+        }
+        [0x5f, 0x36, _, ..] => Err(Error::InvalidLength),
+        [_, _, _, ..] => Err(Error::InvalidCode),
+        _ => Err(Error::InvalidLength),
+    }
+}
+
+impl Currency {
+    /// The numeric code of this currency as two bytes of BCD, e.g. `[0x08, 0x40]` for `840`.
+    #[must_use]
+    pub const fn to_bcd(self) -> [u8; 2] {
+        let number = self as u16;
+
+        // Each digit is less than 10, so these casts never truncate.
+        #[allow(clippy::cast_possible_truncation)]
+        let retval = [
+            (number / 100) as u8,
+            (((number / 10 % 10) << 4) | (number % 10)) as u8,
+        ];
+        retval
+    }
+
+    /// Try to derive a currency from its numeric code as two bytes of BCD.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCode`] when any nibble is not a decimal digit, or the number is not a
+    ///   currency code.
+    pub const fn from_bcd(bytes: [u8; 2]) -> Result<Self, Error> {
+        let mut number = 0;
+        let mut i = 0;
+
+        while i < 4 {
+            let nibble = (bytes[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+
+            match digit(nibble) {
+                Ok(value) => number = number * 10 + value,
+                Err(error) => return Err(error),
+            }
+
+            i += 1;
+        }
+
+        Self::from_u16(number)
+    }
+
+    /// The numeric code of this currency as three ASCII digits, e.g. `*b"008"`.
+    #[must_use]
+    pub const fn to_n3(self) -> [u8; 3] {
+        let bytes = self.numeric_str().as_bytes();
+        [bytes[0], bytes[1], bytes[2]]
+    }
+
+    /// Try to derive a currency from its numeric code as three ASCII digits.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCharset`] when any byte is not an ASCII digit.
+    /// - [`Error::InvalidCode`] when the number is not a currency code.
+    pub const fn from_n3(bytes: [u8; 3]) -> Result<Self, Error> {
+        let mut number = 0;
+        let mut i = 0;
+
+        while i < 3 {
+            if !bytes[i].is_ascii_digit() {
+                return Err(Error::InvalidCharset);
+            }
+
+            number = number * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::from_u16(number)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn currency() {
+        for currency in Currency::ALL {
+            assert_eq!(Ok(currency), Currency::from_bcd(currency.to_bcd()));
+            assert_eq!(Ok(currency), Currency::from_n3(currency.to_n3()));
+            assert_eq!(
+                Ok((currency, &[][..])),
+                read_transaction_currency_code(&transaction_currency_code(currency))
+            );
+            assert_eq!(
+                Ok((currency, &[][..])),
+                read_application_currency_code(&application_currency_code(currency))
+            );
+
+            if let Some(tlv) = transaction_currency_exponent(currency) {
+                assert_eq!(
+                    Ok((currency.minor_unit().unwrap_or_default(), &[][..])),
+                    read_transaction_currency_exponent(&tlv)
+                );
+            }
+        }
+
+        assert_eq!([0x00, 0x08], Currency::Lek.to_bcd());
+        assert_eq!([0x09, 0x78], Currency::Euro.to_bcd());
+        assert_eq!(*b"008", Currency::Lek.to_n3());
+        assert_eq!(
+            Some([0x5f, 0x36, 0x01, 0x00]),
+            transaction_currency_exponent(Currency::Yen)
+        );
+    }
+
+    #[test]
+    fn stream() {
+        const TLVS: &[u8] = &[0x5f, 0x2a, 0x02, 0x09, 0x78, 0x5f, 0x36, 0x01, 0x02, 0x9a];
+
+        let (currency, rest) = read_transaction_currency_code(TLVS).expect("currency");
+        assert_eq!(Currency::Euro, currency);
+        assert_eq!(
+            Ok((2, &[0x9a][..])),
+            read_transaction_currency_exponent(rest)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        const CASES: &[(&[u8], Error)] = &[
+            (&[0x5f, 0x2a, 0x02, 0x08], Error::InvalidLength),
+            (&[0x5f, 0x2a, 0x03, 0x08, 0x40, 0x00], Error::InvalidLength),
+            (&[0x9f, 0x51, 0x02, 0x08, 0x40], Error::InvalidCode),
+            (&[0x5f, 0x2a, 0x02, 0x08, 0x4a], Error::InvalidCode),
+            (&[0x5f, 0x2a, 0x02, 0xf8, 0x40], Error::InvalidCode),
+            (&[0x5f, 0x2a, 0x02, 0x10, 0x00], Error::InvalidCode),
+            (&[0x5f, 0x2a, 0x02, 0x00, 0x00], Error::InvalidCode),
+        ];
+
+        for &(bytes, expected) in CASES {
+            assert_eq!(
+                Err(expected),
+                read_transaction_currency_code(bytes),
+                "{bytes:02x?}"
+            );
+        }
+
+        assert_eq!(Err(Error::InvalidCharset), Currency::from_n3(*b"84O"));
+        assert_eq!(Err(Error::InvalidCode), Currency::from_n3(*b"000"));
+        assert_eq!(Err(Error::InvalidCode), Currency::from_bcd([0x08, 0x4a]));
+        assert_eq!(
+            Err(Error::InvalidCode),
+            read_transaction_currency_exponent(&[0x5f, 0x36, 0x01, 0x0a])
+        );
+        assert_eq!(
+            Err(Error::InvalidCode),
+            read_transaction_currency_exponent(&[0x5f, 0x2a, 0x01, 0x02])
+        );
+    }
+}
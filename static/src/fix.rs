@@ -0,0 +1,264 @@
+//! FIX protocol currency fields
+//!
+//! FIX carries currencies as `Currency`-typed fields, which are exactly three uppercase ASCII
+//! letters of an ISO 4217 code. Values are parsed straight from the bytes of a message, without
+//! copying or allocating:
+//!
+//! ```rust
+//! use iso4217_static::Currency;
+//!
+//! assert_eq!(Ok(Currency::UsDollar), Currency::from_fix(b"USD"));
+//! assert!(Currency::from_fix(b"usd").is_err());
+//! assert_eq!(b"EUR", Currency::Euro.as_fix());
+//! ```
+//!
+//! With the `fix` feature, each standard field of the `Currency` type is also available as a
+//! [`Field`], which knows its tag. The `Currency` (15) field itself is named [`CurrencyField`],
+//! so it doesn't shadow [`Currency`]:
+//!
+//! ```rust
+//! # #[cfg(feature = "fix")]
+//! # {
+//! use iso4217_static::{
+//!     Currency,
+//!     fix::{Field, SettlCurrency},
+//! };
+//!
+//! let field = SettlCurrency::from_field(b"120=JPY\x01").expect("field");
+//! assert_eq!(Currency::Yen, field.0);
+//!
+//! let mut buf = [0; 16];
+//! let len = field.write(&mut buf).expect("buffer");
+//! assert_eq!(b"120=JPY\x01", &buf[..len]);
+//! # }
+//! ```
+
+use crate::{Currency, Error};
+
+/// The FIX field delimiter, `SOH`.
+pub const SOH: u8 = 0x01;
+
+impl Currency {
+    /// Try to derive a currency from the value of a FIX `Currency` field.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidLength`] when the value is not three bytes long.
+    /// - [`Error::InvalidCharset`] when the value is not ASCII.
+    /// - [`Error::InvalidCode`] when the value is not an uppercase currency code.
+    pub const fn from_fix(value: &[u8]) -> Result<Self, Error> {
+        match value {
+            &[a, b, c] => Self::from_bytes([a, b, c]),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// The value of this currency in a FIX `Currency` field.
+    #[must_use]
+    pub const fn as_fix(self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+/// A FIX field with a value of the `Currency` type.
+#[cfg(feature = "fix")]
+pub trait Field: Sized {
+    /// The tag number of this field.
+    const TAG: u32;
+
+    /// Create this field for the given currency.
+    fn new(currency: Currency) -> Self;
+
+    /// The currency of this field.
+    fn currency(&self) -> Currency;
+
+    /// Parse this field from its value.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when the value is not a currency code, see
+    ///   [`Currency::from_fix()`](Currency::from_fix()).
+    fn from_value(value: &[u8]) -> Result<Self, Error> {
+        Currency::from_fix(value).map(Self::new)
+    }
+
+    /// Parse this field from `tag=value`, optionally followed by [`SOH`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCode`] when the tag is not [`Field::TAG`].
+    /// - [`Error`] when the value is not a currency code, see [`Field::from_value()`].
+    fn from_field(field: &[u8]) -> Result<Self, Error> {
+        let field = field.strip_suffix(&[SOH]).unwrap_or(field);
+        let (tag, value) = field
+            .iter()
+            .position(|&b| b == b'=')
+            .map(|position| field.split_at(position))
+            .ok_or(Error::InvalidCode)?;
+
+        if !tag_matches(tag, Self::TAG) {
+            return Err(Error::InvalidCode);
+        }
+
+        Self::from_value(&value[1..])
+    }
+
+    /// Write this field as `tag=value` followed by [`SOH`], returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidLength`] when the buffer is too small.
+    fn write(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let mut digits = [0; 10];
+        let mut start = digits.len();
+        let mut tag = Self::TAG;
+
+        loop {
+            start -= 1;
+            // A single decimal digit always fits in a byte.
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = (tag % 10) as u8;
+            digits[start] = b'0' + digit;
+            tag /= 10;
+
+            if tag == 0 {
+                break;
+            }
+        }
+
+        let tag = &digits[start..];
+        let len = tag.len() + 5;
+        let buf = buf.get_mut(..len).ok_or(Error::InvalidLength)?;
+
+        let (tag_buf, rest) = buf.split_at_mut(tag.len());
+        tag_buf.copy_from_slice(tag);
+        rest[0] = b'=';
+        rest[1..4].copy_from_slice(self.currency().as_fix());
+        rest[4] = SOH;
+
+        Ok(len)
+    }
+}
+
+/// Whether the ASCII tag is the given tag number, without leading zeros.
+#[cfg(feature = "fix")]
+fn tag_matches(tag: &[u8], expected: u32) -> bool {
+    if tag.first() == Some(&b'0') {
+        return false;
+    }
+
+    tag.iter().try_fold(0_u32, |number, &b| {
+        if b.is_ascii_digit() {
+            number.checked_mul(10)?.checked_add(u32::from(b - b'0'))
+        } else {
+            None
+        }
+    }) == Some(expected)
+}
+
+/// Declare a field of the `Currency` type for each FIX field name and tag.
+#[cfg(feature = "fix")]
+macro_rules! fields {
+    ($($(#[$meta:meta])* $name:ident = $tag:literal;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct $name(pub Currency);
+
+            impl Field for $name {
+                const TAG: u32 = $tag;
+
+                fn new(currency: Currency) -> Self {
+                    Self(currency)
+                }
+
+                fn currency(&self) -> Currency {
+                    self.0
+                }
+            }
+
+            impl From<Currency> for $name {
+                fn from(value: Currency) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<$name> for Currency {
+                fn from(value: $name) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "fix")]
+fields! {
+    /// `Currency` (15), the currency of a price or amount.
+    CurrencyField = 15;
+    /// `SettlCurrency` (120), the currency of settlement.
+    SettlCurrency = 120;
+    /// `UnderlyingCurrency` (318), the currency of an underlying instrument.
+    UnderlyingCurrency = 318;
+    /// `CommCurrency` (479), the currency of a commission.
+    CommCurrency = 479;
+    /// `LegCurrency` (556), the currency of a leg.
+    LegCurrency = 556;
+    /// `LegSettlCurrency` (675), the settlement currency of a leg.
+    LegSettlCurrency = 675;
+    /// `AllocSettlCurrency` (736), the settlement currency of an allocation.
+    AllocSettlCurrency = 736;
+    /// `StrikeCurrency` (947), the currency of a strike price.
+    StrikeCurrency = 947;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn value() {
+        const CASES: &[(&[u8], Result<Currency, Error>)] = &[
+            (b"USD", Ok(Currency::UsDollar)),
+            (b"usd", Err(Error::InvalidCode)),
+            (b"USDX", Err(Error::InvalidLength)),
+            (b"", Err(Error::InvalidLength)),
+            (b"U\xc3\xa9", Err(Error::InvalidCharset)),
+            (b"ZZZ", Err(Error::InvalidCode)),
+        ];
+
+        for &(value, expected) in CASES {
+            assert_eq!(expected, Currency::from_fix(value), "{value:?}");
+        }
+
+        for currency in Currency::ALL {
+            assert_eq!(Ok(currency), Currency::from_fix(currency.as_fix()));
+        }
+    }
+
+    #[cfg(feature = "fix")]
+    #[test]
+    fn field() {
+        const CASES: &[(&[u8], Result<CurrencyField, Error>)] = &[
+            (b"15=USD\x01", Ok(CurrencyField(Currency::UsDollar))),
+            (b"15=USD", Ok(CurrencyField(Currency::UsDollar))),
+            (b"015=USD", Err(Error::InvalidCode)),
+            (b"120=USD", Err(Error::InvalidCode)),
+            (b"15USD", Err(Error::InvalidCode)),
+            (b"15=US", Err(Error::InvalidLength)),
+        ];
+
+        for &(field, expected) in CASES {
+            assert_eq!(expected, CurrencyField::from_field(field), "{field:?}");
+        }
+
+        let mut buf = [0; 9];
+        assert_eq!(Ok(8), AllocSettlCurrency(Currency::Euro).write(&mut buf));
+        assert_eq!(b"736=EUR\x01", &buf[..8]);
+        assert_eq!(
+            Err(Error::InvalidLength),
+            AllocSettlCurrency(Currency::Euro).write(&mut buf[..7])
+        );
+    }
+}
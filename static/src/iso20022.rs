@@ -0,0 +1,433 @@
+//! ISO 20022 amounts with a currency
+//!
+//! ISO 20022 messages such as `pain.001`, `pacs.008` and `camt.053` carry amounts as the text
+//! of an element with the code in a `Ccy` attribute, e.g. `<InstdAmt Ccy="EUR">123.45</InstdAmt>`.
+//! The schemas allow at most 18 digits, of which at most 5 fraction digits, and no negative
+//! amounts, and the fraction digits must also fit the minor unit of the currency.
+//!
+//! With the `serde` feature, these types (de)serialize as the `Ccy` attribute and text content
+//! of an element with [`quick-xml`](https://docs.rs/quick-xml):
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use iso4217_static::{Amount, Currency, iso20022::ActiveCurrencyAndAmount};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+//! struct Amt {
+//!     #[serde(rename = "InstdAmt")]
+//!     instructed: ActiveCurrencyAndAmount,
+//! }
+//!
+//! const XML: &str = r#"<Amt><InstdAmt Ccy="EUR">123.45</InstdAmt></Amt>"#;
+//!
+//! let amt: Amt = quick_xml::de::from_str(XML).expect("de");
+//! assert_eq!(Currency::Euro, amt.instructed.currency());
+//! assert_eq!(Amount::new(12_345, 2), amt.instructed.amount());
+//! assert_eq!(XML, quick_xml::se::to_string(&amt).expect("ser"));
+//!
+//! const YEN: &str = r#"<Amt><InstdAmt Ccy="JPY">123.45</InstdAmt></Amt>"#;
+//! assert!(quick_xml::de::from_str::<Amt>(YEN).is_err());
+//! # }
+//! ```
+
+use crate::{Amount, AmountError, Currency, CurrencyKind, Error};
+
+/// The most fraction digits an ISO 20022 amount may have.
+pub const MAX_FRACTION_DIGITS: u8 = 5;
+
+/// The most digits an ISO 20022 amount may have.
+pub const MAX_TOTAL_DIGITS: u8 = 18;
+
+/// Check an amount in the given currency against the schema and the currency's minor unit.
+const fn validate(currency: Currency, amount: Amount) -> Result<(), AmountError> {
+    if amount.is_negative() || amount.total_digits() > MAX_TOTAL_DIGITS {
+        Err(AmountError::OutOfRange)
+    } else if amount.fraction_digits() > MAX_FRACTION_DIGITS || !amount.fits_minor_unit(currency) {
+        Err(AmountError::TooManyFractionDigits)
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether the currency is an active code, as opposed to a code reserved for testing or for
+/// transactions with no currency.
+const fn is_active(currency: Currency) -> bool {
+    !matches!(
+        currency.info().kind,
+        CurrencyKind::Testing | CurrencyKind::NoCurrency
+    )
+}
+
+/// Declare an ISO 20022 amount-with-currency type.
+macro_rules! currency_and_amount {
+    ($(#[$meta:meta])* $name:ident $(, $active:path)?) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct $name {
+            /// The currency of the amount.
+            currency: Currency,
+            /// The amount.
+            amount: Amount,
+        }
+
+        impl $name {
+            /// Create a new amount in the given currency.
+            ///
+            /// # Errors
+            ///
+            /// - [`AmountError::InvalidCurrency`] when the currency is not allowed.
+            /// - [`AmountError::OutOfRange`] when the amount is negative or has more than 18
+            ///   digits.
+            /// - [`AmountError::TooManyFractionDigits`] when the amount has more than 5 fraction
+            ///   digits, or more than the minor unit of the currency.
+            pub const fn new(currency: Currency, amount: Amount) -> Result<Self, AmountError> {
+                $(
+                    if !$active(currency) {
+                        return Err(AmountError::InvalidCurrency(Error::InvalidCode));
+                    }
+                )?
+
+                match validate(currency, amount) {
+                    Ok(()) => Ok(Self { currency, amount }),
+                    Err(error) => Err(error),
+                }
+            }
+
+            /// Parse an amount in the currency with the given code.
+            ///
+            /// # Errors
+            ///
+            /// - [`AmountError::InvalidCurrency`] when the code is not an allowed currency.
+            /// - [`AmountError`] when the amount is not valid, see [`Amount::from_str_slice()`]
+            ///   and
+            #[doc = concat!("[`", stringify!($name), "::new()`].")]
+            pub const fn from_str_slices(currency: &str, amount: &str) -> Result<Self, AmountError> {
+                let currency = match Currency::from_str_slice(currency) {
+                    Ok(currency) => currency,
+                    Err(error) => return Err(AmountError::InvalidCurrency(error)),
+                };
+
+                match Amount::from_str_slice(amount) {
+                    Ok(amount) => Self::new(currency, amount),
+                    Err(error) => Err(error),
+                }
+            }
+
+            /// The currency of the amount.
+            #[must_use]
+            pub const fn currency(&self) -> Currency {
+                self.currency
+            }
+
+            /// The amount.
+            #[must_use]
+            pub const fn amount(&self) -> Amount {
+                self.amount
+            }
+        }
+
+        impl TryFrom<(Currency, Amount)> for $name {
+            type Error = AmountError;
+
+            fn try_from((currency, amount): (Currency, Amount)) -> Result<Self, Self::Error> {
+                Self::new(currency, amount)
+            }
+        }
+
+        impl From<$name> for (Currency, Amount) {
+            fn from(value: $name) -> Self {
+                (value.currency, value.amount)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde_impl::serialize(stringify!($name), self.currency, self.amount, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let (currency, amount) = serde_impl::deserialize(stringify!($name), deserializer)?;
+
+                Self::new(currency, amount).map_err(|error| {
+                    <D::Error as serde::de::Error>::custom(error)
+                })
+            }
+        }
+    };
+}
+
+currency_and_amount!(
+    /// An amount in an active currency, the `ActiveCurrencyAndAmount` type, which rejects
+    /// the codes reserved for testing (`XTS`) and for no currency (`XXX`).
+    ActiveCurrencyAndAmount,
+    is_active
+);
+
+currency_and_amount!(
+    /// An amount in an active or historic currency, the `ActiveOrHistoricCurrencyAndAmount`
+    /// type, which accepts every currency in the List One data this crate was generated from,
+    /// including `XTS` and `XXX`.
+    ///
+    /// Historic codes, such as `DEM`, are not in that data, so unlike the ISO 20022 type they
+    /// are rejected with [`AmountError::InvalidCurrency`] when parsed.
+    ActiveOrHistoricCurrencyAndAmount
+);
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use crate::{Amount, AmountError, Currency};
+    use core::fmt::{Display, Formatter, Result as FmtResult};
+    use serde::{
+        Deserializer, Serializer,
+        de::{Error as DeError, IgnoredAny, MapAccess, Unexpected, Visitor},
+        ser::SerializeStruct,
+    };
+
+    /// The name of the currency attribute.
+    const CCY: &str = "@Ccy";
+
+    /// The name of the amount text content.
+    const TEXT: &str = "$text";
+
+    /// An [`Amount`] displayed as its text.
+    struct Text(Amount);
+
+    impl Display for Text {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            self.0.write(f, '.')
+        }
+    }
+
+    impl serde::Serialize for Text {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    /// Serialize a currency and amount as an element with a `Ccy` attribute.
+    pub(super) fn serialize<S: Serializer>(
+        name: &'static str,
+        currency: Currency,
+        amount: Amount,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(name, 2)?;
+        state.serialize_field(CCY, currency.as_str())?;
+        state.serialize_field(TEXT, &Text(amount))?;
+        state.end()
+    }
+
+    /// Deserialize a currency and amount from an element with a `Ccy` attribute.
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        name: &'static str,
+        deserializer: D,
+    ) -> Result<(Currency, Amount), D::Error> {
+        deserializer.deserialize_struct(name, &[CCY, TEXT], CurrencyAndAmountVisitor)
+    }
+
+    /// A visitor for the currency attribute and amount text of an element.
+    struct CurrencyAndAmountVisitor;
+
+    impl<'de> Visitor<'de> for CurrencyAndAmountVisitor {
+        type Value = (Currency, Amount);
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("an amount with a currency")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut currency = None;
+            let mut amount = None;
+
+            while let Some(key) = map.next_key::<Key>()? {
+                match key {
+                    Key::Ccy => {
+                        currency = Some(map.next_value_seed(StrSeed(parse_currency))?);
+                    }
+                    Key::Text => amount = Some(map.next_value_seed(StrSeed(parse_amount))?),
+                    Key::Other => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+
+            Ok((
+                currency.ok_or_else(|| A::Error::missing_field(CCY))?,
+                amount.ok_or_else(|| A::Error::missing_field(TEXT))?,
+            ))
+        }
+    }
+
+    /// Parse a currency code.
+    fn parse_currency(value: &str) -> Result<Currency, AmountError> {
+        Currency::from_str_slice(value).map_err(AmountError::InvalidCurrency)
+    }
+
+    /// Parse an amount, which XML allows to be surrounded by whitespace.
+    fn parse_amount(value: &str) -> Result<Amount, AmountError> {
+        Amount::from_str_slice(value.trim())
+    }
+
+    /// Parse the name of a field.
+    #[allow(clippy::unnecessary_wraps)]
+    fn parse_key(value: &str) -> Result<Key, AmountError> {
+        Ok(match value {
+            CCY | "Ccy" => Key::Ccy,
+            TEXT => Key::Text,
+            _ => Key::Other,
+        })
+    }
+
+    /// The fields of an element.
+    enum Key {
+        /// The `Ccy` attribute.
+        Ccy,
+        /// The text content.
+        Text,
+        /// Anything else, which is ignored.
+        Other,
+    }
+
+    impl<'de> serde::Deserialize<'de> for Key {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_identifier(StrSeed(parse_key))
+        }
+    }
+
+    /// Deserialize a string with the given parser, without allocating.
+    struct StrSeed<F>(F);
+
+    impl<'de, T, F: FnOnce(&str) -> Result<T, AmountError>> serde::de::DeserializeSeed<'de>
+        for StrSeed<F>
+    {
+        type Value = T;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<T, F: FnOnce(&str) -> Result<T, AmountError>> Visitor<'_> for StrSeed<F> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+            (self.0)(v).map_err(|error| match error {
+                AmountError::InvalidFormat | AmountError::InvalidCurrency(_) => {
+                    E::invalid_value(Unexpected::Str(v), &expected(error))
+                }
+                _ => E::custom(error),
+            })
+        }
+    }
+
+    /// What was expected instead of the value which caused the given error.
+    fn expected(error: AmountError) -> &'static str {
+        match error {
+            AmountError::InvalidCurrency(_) => "a currency code",
+            _ => "a decimal amount",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        const CASES: &[(&str, &str, Result<(), AmountError>)] = &[
+            ("EUR", "123.45", Ok(())),
+            ("EUR", "123.450", Ok(())),
+            ("EUR", "123.456", Err(AmountError::TooManyFractionDigits)),
+            ("JPY", "100", Ok(())),
+            ("JPY", "100.5", Err(AmountError::TooManyFractionDigits)),
+            ("XAU", "1.25", Ok(())),
+            ("XAU", "1.123456", Err(AmountError::TooManyFractionDigits)),
+            ("EUR", "-1", Err(AmountError::OutOfRange)),
+            ("EUR", "1234567890123456789", Err(AmountError::OutOfRange)),
+            ("EUR", "123456789012345678", Ok(())),
+            ("EUR", "1,00", Err(AmountError::InvalidFormat)),
+            (
+                "EUX",
+                "1",
+                Err(AmountError::InvalidCurrency(Error::InvalidCode)),
+            ),
+            (
+                "XTS",
+                "1",
+                Err(AmountError::InvalidCurrency(Error::InvalidCode)),
+            ),
+            (
+                "XXX",
+                "1",
+                Err(AmountError::InvalidCurrency(Error::InvalidCode)),
+            ),
+        ];
+
+        for &(currency, amount, expected) in CASES {
+            assert_eq!(
+                expected,
+                ActiveCurrencyAndAmount::from_str_slices(currency, amount).map(|_value| ()),
+                "{currency} {amount}"
+            );
+        }
+
+        assert!(ActiveOrHistoricCurrencyAndAmount::from_str_slices("XTS", "1").is_ok());
+        assert!(ActiveOrHistoricCurrencyAndAmount::from_str_slices("XXX", "1").is_ok());
+        assert_eq!(
+            Err(AmountError::InvalidCurrency(Error::InvalidCode)),
+            ActiveOrHistoricCurrencyAndAmount::from_str_slices("DEM", "1")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn xml() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+        struct Document {
+            #[serde(rename = "InstdAmt")]
+            instructed: ActiveCurrencyAndAmount,
+            #[serde(rename = "Amt")]
+            amount: ActiveOrHistoricCurrencyAndAmount,
+        }
+
+        const XML: &str = concat!(
+            "<Document>",
+            r#"<InstdAmt Ccy="EUR">123.45</InstdAmt>"#,
+            r#"<Amt Ccy="XTS">0.5</Amt>"#,
+            "</Document>",
+        );
+        const INVALID: &[&str] = &[
+            r#"<Document><InstdAmt Ccy="XXX">1</InstdAmt><Amt Ccy="XTS">1</Amt></Document>"#,
+            r#"<Document><InstdAmt Ccy="JPY">1.5</InstdAmt><Amt Ccy="XTS">1</Amt></Document>"#,
+            r#"<Document><InstdAmt Ccy="EUR">-1</InstdAmt><Amt Ccy="XTS">1</Amt></Document>"#,
+            r#"<Document><InstdAmt>1</InstdAmt><Amt Ccy="XTS">1</Amt></Document>"#,
+            r#"<Document><InstdAmt Ccy="EUR">1</InstdAmt><Amt Ccy="DEM">1</Amt></Document>"#,
+        ];
+
+        let document = quick_xml::de::from_str::<Document>(XML).expect("de");
+        assert_eq!(Currency::Euro, document.instructed.currency());
+        assert_eq!(Amount::new(12_345, 2), document.instructed.amount());
+        assert_eq!(
+            Currency::from_str_slice("XTS"),
+            Ok(document.amount.currency())
+        );
+        assert_eq!(XML, quick_xml::se::to_string(&document).expect("ser"));
+
+        for xml in INVALID {
+            assert!(quick_xml::de::from_str::<Document>(xml).is_err(), "{xml}");
+        }
+    }
+}
@@ -3,13 +3,43 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
+pub mod amount;
+#[cfg(test)]
+mod buf;
+pub mod emv;
+pub mod fix;
+pub mod iso20022;
+pub mod map;
+pub mod packed;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+pub mod search;
 #[cfg(feature = "serde")]
 pub mod serde;
+pub mod set;
+pub mod swift;
+pub mod xbrl;
+
+pub use crate::{
+    amount::{Amount, AmountError},
+    map::CurrencyMap,
+    packed::PackedCode,
+    search::Search,
+    set::CurrencySet,
+};
 
 use core::str::FromStr;
 use iso3166_static::{Alpha2, Alpha3, Numeric};
 
-iso4217_macros::generate!(xml = "list-one.xml", zerocopy = true);
+iso4217_macros::generate!(
+    xml = "list-one.xml",
+    idents = "idents.json",
+    metadata = true,
+    zerocopy = true
+);
+
+/// The ISO 4217 List One XML document the data in this crate was generated from.
+pub const SOURCE_XML: &str = include_str!("list-one.xml");
 
 impl Error {
     /// Whether this error is of the `InvalidCode` variant.
@@ -96,6 +126,49 @@ mod test {
     use super::*;
     use iso3166_static::{Alpha2, Alpha3, Numeric};
 
+    /// The codes used for the settlement subset.
+    const SETTLEMENT: &[&str] = &[
+        "AUD", "BRL", "CAD", "CHF", "CNY", "CZK", "DKK", "EUR", "GBP", "HKD", "HUF", "IDR", "ILS",
+        "INR", "ISK", "JPY", "KRW", "MXN", "MYR", "NOK", "NZD", "PHP", "PLN", "RON", "SEK", "SGD",
+        "THB", "TRY", "USD", "ZAR",
+    ];
+
+    mod settlement {
+        iso4217_macros::generate!(
+            xml = "list-one.xml",
+            type_name = "SettlementCurrency",
+            error_name = "SettlementError",
+            naming = "code",
+            methods = "string",
+            include = "AUD, BRL, CAD, CHF, CNY, CZK, DKK, EUR, GBP, HKD, HUF, IDR, ILS, INR, ISK, JPY, KRW, MXN, MYR, NOK, NZD, PHP, PLN, RON, SEK, SGD, THB, TRY, USD, ZAR",
+            convert = "crate::Currency"
+        );
+    }
+
+    mod local {
+        iso4217_macros::generate!(
+            xml = "list-one.xml",
+            idents = "idents.json",
+            type_name = "LocalCurrency",
+            error_name = "LocalError",
+            methods = "string, country",
+            funds = false,
+            x_codes = false,
+            countries = "Switzerland, UnitedStatesOfAmerica, Ecuador"
+        );
+    }
+
+    mod table {
+        iso4217_macros::generate!(
+            xml = "list-one.xml",
+            idents = "idents.json",
+            type_name = "TableCurrency",
+            error_name = "TableError",
+            methods = "numeric, string, name, fund, minor_unit, index",
+            lookup = "table"
+        );
+    }
+
     #[test]
     fn for_country() {
         const NO_CURRENCY: &[Numeric] = &[
@@ -135,4 +208,248 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn settlement_subset() {
+        use settlement::{SettlementCurrency, SettlementError};
+
+        for code in SETTLEMENT {
+            let settlement = SettlementCurrency::from_str_slice(code).expect("settlement");
+            let currency = Currency::from(settlement);
+
+            assert_eq!(*code, settlement.as_str());
+            assert_eq!(*code, currency.as_str());
+            assert_eq!(Ok(settlement), SettlementCurrency::try_from(currency));
+        }
+
+        assert_eq!(Currency::UsDollar, Currency::from(SettlementCurrency::USD));
+        assert_eq!(
+            Ok(SettlementCurrency::CHF),
+            SettlementCurrency::try_from(Currency::SwissFranc)
+        );
+
+        for code in ["USN", "XAU", "ARS"] {
+            let currency = Currency::from_str_slice(code).expect("currency");
+
+            assert_eq!(
+                Err(SettlementError::InvalidCode),
+                SettlementCurrency::try_from(currency)
+            );
+            assert_eq!(
+                Err(SettlementError::InvalidCode),
+                SettlementCurrency::from_str_slice(code)
+            );
+        }
+    }
+
+    #[test]
+    fn alpha2_countries() {
+        assert_eq!(
+            &[Alpha2::Liechtenstein, Alpha2::Switzerland],
+            Currency::SwissFranc.alpha2_countries()
+        );
+        assert_eq!(
+            Ok([Alpha2::Switzerland].as_slice()),
+            Currency::from_str_slice("CHE").map(Currency::alpha2_countries)
+        );
+        assert!(Currency::Euro.alpha2_countries().contains(&Alpha2::France));
+        assert!(Currency::Gold.alpha2_countries().is_empty());
+
+        for currency in Currency::ALL {
+            for &country in currency.alpha2_countries() {
+                let primary = Currency::from_alpha2_country(country).expect("primary");
+                assert!(primary.alpha2_countries().contains(&country));
+            }
+        }
+    }
+
+    #[test]
+    fn country_subset() {
+        use local::LocalCurrency;
+
+        for code in ["CHF", "USD"] {
+            assert!(LocalCurrency::from_str_slice(code).is_ok());
+        }
+
+        for code in ["CHE", "CHW", "USN", "EUR", "XAU"] {
+            assert!(LocalCurrency::from_str_slice(code).is_err());
+        }
+
+        assert_eq!(
+            LocalCurrency::from_str_slice("CHF").ok(),
+            LocalCurrency::from_alpha2_country(Alpha2::Liechtenstein)
+        );
+        assert_eq!(
+            LocalCurrency::from_str_slice("USD").ok(),
+            LocalCurrency::from_alpha2_country(Alpha2::PuertoRico)
+        );
+        assert_eq!(None, LocalCurrency::from_alpha2_country(Alpha2::France));
+    }
+
+    #[test]
+    fn metadata() {
+        assert_eq!(
+            chrono::NaiveDate::from_ymd_opt(2026, 1, 1),
+            Some(Currency::PUBLISHED)
+        );
+        assert_eq!(64, Currency::SOURCE_SHA256.len());
+        assert!(
+            Currency::SOURCE_SHA256
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+        );
+        assert_eq!(env!("CARGO_PKG_VERSION"), Currency::GENERATOR_VERSION);
+        assert_eq!(
+            Currency::COUNT,
+            (0..=999)
+                .filter(|&number| Currency::from_u16(number).is_ok())
+                .count()
+        );
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(Currency::COUNT, Currency::ALL.len());
+        assert!(
+            Currency::ALL
+                .windows(2)
+                .all(|pair| (pair[0] as u16) < (pair[1] as u16))
+        );
+
+        for (index, currency) in Currency::ALL.into_iter().enumerate() {
+            assert_eq!(index, currency.index());
+            assert_eq!(Some(currency), Currency::from_index(index));
+        }
+
+        assert_eq!(None, Currency::from_index(Currency::COUNT));
+    }
+
+    #[test]
+    fn info() {
+        let info = Currency::UsDollar.info();
+
+        assert_eq!(Currency::UsDollar, info.currency);
+        assert_eq!("USD", info.code);
+        assert_eq!(840, info.number);
+        assert_eq!("840", info.numeric);
+        assert_eq!(Some(2), info.minor_unit);
+        assert_eq!(CurrencyKind::LegalTender, info.kind);
+        assert!(info.countries.contains(&"UNITED STATES OF AMERICA (THE)"));
+        assert_eq!("008", Currency::Lek.info().numeric);
+
+        for currency in Currency::ALL {
+            let info = currency.info();
+
+            assert_eq!(currency, info.currency);
+            assert_eq!(currency.as_str(), info.code);
+            assert_eq!(currency as u16, info.number);
+            assert_eq!(currency.name(), info.name);
+            assert_eq!(currency.is_fund(), info.is_fund);
+            assert_eq!(currency.minor_unit(), info.minor_unit);
+        }
+    }
+
+    #[test]
+    fn kind() {
+        const KINDS: &[(&str, CurrencyKind)] = &[
+            ("EUR", CurrencyKind::LegalTender),
+            ("XOF", CurrencyKind::LegalTender),
+            ("CHE", CurrencyKind::Fund),
+            ("USN", CurrencyKind::Fund),
+            ("XDR", CurrencyKind::UnitOfAccount),
+            ("XBA", CurrencyKind::UnitOfAccount),
+            ("XAU", CurrencyKind::PreciousMetal),
+            ("XTS", CurrencyKind::Testing),
+            ("XXX", CurrencyKind::NoCurrency),
+        ];
+
+        for &(code, kind) in KINDS {
+            let currency = Currency::from_str_slice(code).expect("code");
+            assert_eq!(kind, currency.info().kind, "{code}");
+        }
+
+        let info = Currency::from_str_slice("XXX").expect("code").info();
+        assert!(info.countries.is_empty());
+    }
+
+    #[test]
+    fn table_lookup() {
+        use table::{TableCurrency, TableError};
+
+        assert_eq!(Currency::COUNT, TableCurrency::COUNT);
+
+        for number in 0..=999 {
+            assert_eq!(
+                Currency::from_u16(number).map(|currency| currency as u16),
+                TableCurrency::from_u16(number)
+                    .map(|currency| currency as u16)
+                    .map_err(|_error| Error::InvalidCode),
+            );
+        }
+
+        for (index, currency) in Currency::ALL.into_iter().enumerate() {
+            let table = TableCurrency::from_str_slice(currency.as_str()).expect("code");
+
+            assert_eq!(currency as u16, table as u16);
+            assert_eq!(index, table.index());
+            assert_eq!(Some(table), TableCurrency::from_index(index));
+            assert_eq!(currency.as_str(), table.as_str());
+            assert_eq!(currency.name(), table.name());
+            assert_eq!(currency.is_fund(), table.is_fund());
+            assert_eq!(currency.minor_unit(), table.minor_unit());
+        }
+
+        assert_eq!(None, TableCurrency::from_index(TableCurrency::COUNT));
+        assert_eq!(
+            Err(TableError::InvalidCode),
+            TableCurrency::from_str_slice("usd")
+        );
+        assert_eq!(
+            Err(TableError::InvalidCode),
+            TableCurrency::from_str_slice("ZZZ")
+        );
+        assert_eq!(
+            Err(TableError::InvalidLength),
+            TableCurrency::from_str_slice("US")
+        );
+    }
+
+    #[test]
+    fn ignore_case() {
+        const CASES: &[(&str, Result<Currency, Error>)] = &[
+            ("usd", Ok(Currency::UsDollar)),
+            ("Eur", Ok(Currency::Euro)),
+            ("JPY", Ok(Currency::Yen)),
+            ("zzz", Err(Error::InvalidCode)),
+            ("us", Err(Error::InvalidLength)),
+            ("usd ", Err(Error::InvalidLength)),
+            ("u\u{e9}", Err(Error::InvalidCharset)),
+        ];
+
+        for &(value, expected) in CASES {
+            assert_eq!(expected, Currency::from_str_ignore_case(value), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn lenient() {
+        const CASES: &[(&str, Result<Currency, Error>)] = &[
+            ("USD", Ok(Currency::UsDollar)),
+            (" usd\n", Ok(Currency::UsDollar)),
+            ("840", Ok(Currency::UsDollar)),
+            ("\t008 ", Ok(Currency::Lek)),
+            ("8", Ok(Currency::Lek)),
+            ("", Err(Error::InvalidLength)),
+            ("   ", Err(Error::InvalidLength)),
+            ("0840", Err(Error::InvalidLength)),
+            ("US D", Err(Error::InvalidLength)),
+            ("84O", Err(Error::InvalidCode)),
+            ("998", Err(Error::InvalidCode)),
+            ("x\u{e9}", Err(Error::InvalidCharset)),
+        ];
+
+        for &(value, expected) in CASES {
+            assert_eq!(expected, Currency::from_str_lenient(value), "{value:?}");
+        }
+    }
 }
@@ -0,0 +1,204 @@
+//! Fixed-size currency maps
+
+use crate::{Currency, CurrencySet};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+/// A map from currencies to values, stored as a fixed-size array indexed by [`Currency::index()`].
+///
+/// This neither allocates nor hashes, and iterates in numeric code order:
+///
+/// ```rust
+/// use iso4217_static::{Currency, CurrencyMap};
+///
+/// let mut limits = CurrencyMap::new();
+/// limits.insert(Currency::UsDollar, 10_000);
+/// limits.insert(Currency::Euro, 9_000);
+///
+/// assert_eq!(Some(&10_000), limits.get(Currency::UsDollar));
+/// assert_eq!(None, limits.get(Currency::Yen));
+/// assert!(limits.keys().eq([Currency::UsDollar, Currency::Euro]));
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct CurrencyMap<V> {
+    /// The value for each currency, by index.
+    values: [Option<V>; Currency::COUNT],
+}
+
+impl<V> CurrencyMap<V> {
+    /// Create a new, empty map.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            values: [const { None }; Currency::COUNT],
+        }
+    }
+
+    /// Create a map with a value for every currency.
+    pub fn from_fn(mut f: impl FnMut(Currency) -> V) -> Self {
+        Self {
+            values: Currency::ALL.map(|currency| Some(f(currency))),
+        }
+    }
+
+    /// The value for the given currency, if there is one.
+    #[must_use]
+    pub const fn get(&self, currency: Currency) -> Option<&V> {
+        self.values[currency.index()].as_ref()
+    }
+
+    /// The mutable value for the given currency, if there is one.
+    pub const fn get_mut(&mut self, currency: Currency) -> Option<&mut V> {
+        self.values[currency.index()].as_mut()
+    }
+
+    /// Whether the map has a value for the given currency.
+    #[must_use]
+    pub const fn contains_key(&self, currency: Currency) -> bool {
+        self.values[currency.index()].is_some()
+    }
+
+    /// Set the value for the given currency, returning the previous value, if any.
+    pub fn insert(&mut self, currency: Currency, value: V) -> Option<V> {
+        self.values[currency.index()].replace(value)
+    }
+
+    /// Remove the value for the given currency, returning it, if any.
+    pub fn remove(&mut self, currency: Currency) -> Option<V> {
+        self.values[currency.index()].take()
+    }
+
+    /// Remove every value from the map.
+    pub fn clear(&mut self) {
+        self.values.iter_mut().for_each(|value| *value = None);
+    }
+
+    /// The number of currencies with a value.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.iter().filter(|value| value.is_some()).count()
+    }
+
+    /// Whether the map has no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(Option::is_none)
+    }
+
+    /// Iterate over the currencies and their values, in numeric code order.
+    pub fn iter(&self) -> impl Iterator<Item = (Currency, &V)> {
+        Currency::ALL
+            .into_iter()
+            .zip(&self.values)
+            .filter_map(|(currency, value)| value.as_ref().map(|value| (currency, value)))
+    }
+
+    /// Iterate over the currencies and their mutable values, in numeric code order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Currency, &mut V)> {
+        Currency::ALL
+            .into_iter()
+            .zip(&mut self.values)
+            .filter_map(|(currency, value)| value.as_mut().map(|value| (currency, value)))
+    }
+
+    /// Iterate over the currencies with a value, in numeric code order.
+    pub fn keys(&self) -> impl Iterator<Item = Currency> {
+        self.iter().map(|(currency, _value)| currency)
+    }
+
+    /// Iterate over the values, in numeric code order of their currencies.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.values.iter().flatten()
+    }
+
+    /// The set of currencies with a value.
+    #[must_use]
+    pub fn key_set(&self) -> CurrencySet {
+        self.keys().collect()
+    }
+}
+
+impl<V> Default for CurrencyMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Debug> Debug for CurrencyMap<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> FromIterator<(Currency, V)> for CurrencyMap<V> {
+    fn from_iter<T: IntoIterator<Item = (Currency, V)>>(iter: T) -> Self {
+        let mut retval = Self::new();
+        retval.extend(iter);
+        retval
+    }
+}
+
+impl<V> Extend<(Currency, V)> for CurrencyMap<V> {
+    fn extend<T: IntoIterator<Item = (Currency, V)>>(&mut self, iter: T) {
+        for (currency, value) in iter {
+            self.insert(currency, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_remove() {
+        let mut map = CurrencyMap::new();
+
+        assert!(map.is_empty());
+        assert_eq!(None, map.insert(Currency::UsDollar, 1));
+        assert_eq!(Some(1), map.insert(Currency::UsDollar, 2));
+        assert!(map.contains_key(Currency::UsDollar));
+        assert_eq!(1, map.len());
+
+        if let Some(value) = map.get_mut(Currency::UsDollar) {
+            *value += 1;
+        }
+
+        assert_eq!(Some(&3), map.get(Currency::UsDollar));
+        assert_eq!(Some(3), map.remove(Currency::UsDollar));
+        assert_eq!(None, map.remove(Currency::UsDollar));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        let map = [
+            (Currency::Yen, "JPY"),
+            (Currency::Lek, "ALL"),
+            (Currency::UsDollar, "USD"),
+        ]
+        .into_iter()
+        .collect::<CurrencyMap<_>>();
+
+        assert!(map.iter().eq([
+            (Currency::Lek, &"ALL"),
+            (Currency::Yen, &"JPY"),
+            (Currency::UsDollar, &"USD"),
+        ]));
+        assert!(map.values().eq([&"ALL", &"JPY", &"USD"]));
+        assert_eq!(
+            CurrencySet::from_slice(&[Currency::Lek, Currency::Yen, Currency::UsDollar]),
+            map.key_set()
+        );
+    }
+
+    #[test]
+    fn from_fn() {
+        let map = CurrencyMap::from_fn(|currency| currency.as_str());
+
+        assert_eq!(Currency::COUNT, map.len());
+        assert!(
+            map.iter()
+                .all(|(currency, code)| currency.as_str() == *code)
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Packed currency codes
+
+use crate::{Currency, Error};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A three-letter code packed into 15 bits, with 5 bits per letter, which need not be a known
+/// currency.
+///
+/// Packed codes sort in the same order as the codes themselves, which makes them suitable for
+/// database keys and hash partitioning:
+///
+/// ```rust
+/// use iso4217_static::{Currency, PackedCode};
+///
+/// let usd = Currency::UsDollar.to_packed();
+/// assert_eq!(Ok(Currency::UsDollar), usd.currency());
+///
+/// // Codes which are not (yet) currencies still round-trip.
+/// let unknown = PackedCode::from_str_slice("ZZZ").expect("letters");
+/// assert_eq!(*b"ZZZ", PackedCode::from_bits(unknown.to_bits()).expect("bits").to_bytes());
+/// assert!(unknown.currency().is_err());
+/// assert!(usd < unknown);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct PackedCode(u16);
+
+impl PackedCode {
+    /// Pack the given three uppercase ASCII letters.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCharset`] when any of the bytes is not ASCII.
+    /// - [`Error::InvalidCode`] when any of the bytes is not an uppercase letter.
+    pub const fn from_bytes(bytes: [u8; 3]) -> Result<Self, Error> {
+        if !bytes.is_ascii() {
+            return Err(Error::InvalidCharset);
+        }
+
+        let mut retval = 0;
+        let mut i = 0;
+
+        while i < 3 {
+            if !bytes[i].is_ascii_uppercase() {
+                return Err(Error::InvalidCode);
+            }
+
+            retval = (retval << 5) | (bytes[i] - b'A') as u16;
+            i += 1;
+        }
+
+        Ok(Self(retval))
+    }
+
+    /// Pack the given three-letter string.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidLength`] when the string is not three bytes long.
+    /// - [`Error`] when the bytes cannot be packed, see [`PackedCode::from_bytes()`].
+    pub const fn from_str_slice(value: &str) -> Result<Self, Error> {
+        match value.as_bytes() {
+            &[a, b, c] => Self::from_bytes([a, b, c]),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// Read a packed code from its 15 bits, as returned by [`PackedCode::to_bits()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCode`] when the top bit is set, or any 5 bits are not a letter.
+    pub const fn from_bits(bits: u16) -> Result<Self, Error> {
+        if bits >> 15 != 0
+            || (bits >> 10) & 0x1f >= 26
+            || (bits >> 5) & 0x1f >= 26
+            || bits & 0x1f >= 26
+        {
+            return Err(Error::InvalidCode);
+        }
+
+        Ok(Self(bits))
+    }
+
+    /// The 15 bits of this packed code.
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// The three uppercase ASCII letters of this packed code.
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 3] {
+        // Each letter is less than 26, so these casts never truncate.
+        #[allow(clippy::cast_possible_truncation)]
+        [
+            b'A' + ((self.0 >> 10) & 0x1f) as u8,
+            b'A' + ((self.0 >> 5) & 0x1f) as u8,
+            b'A' + (self.0 & 0x1f) as u8,
+        ]
+    }
+
+    /// The currency with this code.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCode`] when this is not a currency code.
+    pub const fn currency(self) -> Result<Currency, Error> {
+        Currency::from_bytes(self.to_bytes())
+    }
+}
+
+impl Display for PackedCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let bytes = self.to_bytes();
+        // Packed codes are always uppercase ASCII letters.
+        f.write_str(core::str::from_utf8(&bytes).map_err(|_error| core::fmt::Error)?)
+    }
+}
+
+impl From<Currency> for PackedCode {
+    fn from(value: Currency) -> Self {
+        value.to_packed()
+    }
+}
+
+impl From<PackedCode> for u16 {
+    fn from(value: PackedCode) -> Self {
+        value.to_bits()
+    }
+}
+
+impl TryFrom<u16> for PackedCode {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::from_bits(value)
+    }
+}
+
+impl TryFrom<[u8; 3]> for PackedCode {
+    type Error = Error;
+
+    fn try_from(value: [u8; 3]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
+    }
+}
+
+impl TryFrom<PackedCode> for Currency {
+    type Error = Error;
+
+    fn try_from(value: PackedCode) -> Result<Self, Self::Error> {
+        value.currency()
+    }
+}
+
+impl Currency {
+    /// Try to derive a currency from the given three ASCII bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when the bytes are not a currency code, see [`Currency::from_str_slice()`].
+    pub const fn from_bytes(bytes: [u8; 3]) -> Result<Self, Error> {
+        match core::str::from_utf8(&bytes) {
+            Ok(value) => Self::from_str_slice(value),
+            Err(_) => Err(Error::InvalidCharset),
+        }
+    }
+
+    /// The three ASCII bytes of the code for this currency.
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 3] {
+        let bytes = self.as_str().as_bytes();
+        [bytes[0], bytes[1], bytes[2]]
+    }
+
+    /// The numeric code for this currency, zero-padded to three digits (e.g. `"008"`).
+    #[must_use]
+    pub const fn numeric_str(self) -> &'static str {
+        self.info().numeric
+    }
+
+    /// The code for this currency, packed into 15 bits.
+    #[must_use]
+    pub const fn to_packed(self) -> PackedCode {
+        // Currency codes are always three uppercase letters.
+        let bytes = self.to_bytes();
+        PackedCode(
+            ((bytes[0].wrapping_sub(b'A') as u16) << 10)
+                | ((bytes[1].wrapping_sub(b'A') as u16) << 5)
+                | bytes[2].wrapping_sub(b'A') as u16,
+        )
+    }
+}
+
+impl From<Currency> for [u8; 3] {
+    fn from(value: Currency) -> Self {
+        value.to_bytes()
+    }
+}
+
+impl TryFrom<[u8; 3]> for Currency {
+    type Error = Error;
+
+    fn try_from(value: [u8; 3]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn currency() {
+        for currency in Currency::ALL {
+            let packed = currency.to_packed();
+
+            assert_eq!(currency.as_str().as_bytes(), currency.to_bytes());
+            assert_eq!(Ok(currency), Currency::try_from(currency.to_bytes()));
+            assert_eq!(Ok(packed), PackedCode::from_bits(packed.to_bits()));
+            assert_eq!(Ok(currency), packed.currency());
+            assert_eq!(3, currency.numeric_str().len());
+            assert_eq!(
+                Ok(currency as u16),
+                currency.numeric_str().parse::<u16>().map_err(|_error| ())
+            );
+        }
+
+        assert_eq!("008", Currency::Lek.numeric_str());
+        assert_eq!(*b"USD", Currency::UsDollar.to_bytes());
+    }
+
+    #[test]
+    fn order() {
+        let mut packed = Currency::ALL.map(Currency::to_packed);
+        packed.sort_unstable();
+
+        let mut codes = Currency::ALL.map(Currency::to_bytes);
+        codes.sort_unstable();
+
+        assert_eq!(codes, packed.map(PackedCode::to_bytes));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Ok(0),
+            PackedCode::from_bytes(*b"AAA").map(PackedCode::to_bits)
+        );
+        assert_eq!(Err(Error::InvalidCode), PackedCode::from_bytes(*b"usd"));
+        assert_eq!(
+            Err(Error::InvalidCharset),
+            PackedCode::from_bytes([b'U', 0xc3, 0xa9])
+        );
+        assert_eq!(
+            Err(Error::InvalidCharset),
+            PackedCode::from_bytes([b'u', 0xc3, 0xa9])
+        );
+        assert_eq!(
+            Err(Error::InvalidCharset),
+            Currency::from_bytes([b'u', 0xc3, 0xa9])
+        );
+        assert_eq!(
+            Err(Error::InvalidLength),
+            PackedCode::from_str_slice("USDX")
+        );
+        assert_eq!(Err(Error::InvalidCode), PackedCode::from_bits(0x8000));
+        assert_eq!(Err(Error::InvalidCode), PackedCode::from_bits(26));
+        assert_eq!(
+            Err(Error::InvalidCharset),
+            Currency::from_bytes([b'U', 0xc3, 0xa9])
+        );
+        assert_eq!(Err(Error::InvalidCode), Currency::from_bytes(*b"ZZZ"));
+    }
+}
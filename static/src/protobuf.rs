@@ -0,0 +1,352 @@
+//! Protocol buffers interoperability
+//!
+//! [`Money`] is an amount in a currency with the shape of the well-known `google.type.Money`
+//! message: a currency code, whole `units`, and `nanos` (10^-9 units) with the same sign as the
+//! units. It converts exactly to and from an [`Amount`], and fails rather than rounding when the
+//! amount does not fit the minor unit of the currency:
+//!
+//! ```rust
+//! use iso4217_static::{Amount, Currency, protobuf::Money};
+//!
+//! let money = Money::new(Currency::UsDollar, Amount::new(-175, 2)).expect("money");
+//! assert_eq!(("USD", -1, -750_000_000), money.into_parts());
+//!
+//! let money = Money::from_parts("USD", -1, -750_000_000).expect("money");
+//! assert_eq!(Amount::new(-175, 2), money.amount());
+//!
+//! assert!(Money::from_parts("JPY", 1, 500_000_000).is_err());
+//! ```
+//!
+//! Currencies also map to and from `i32` by their numeric code, with the same methods as an
+//! enumeration generated by [`prost`](https://docs.rs/prost), so they can be used in place of
+//! one. Zero, the `UNSPECIFIED` value by convention, is not a currency:
+//!
+//! ```rust
+//! use iso4217_static::Currency;
+//!
+//! assert_eq!(840, i32::from(Currency::UsDollar));
+//! assert_eq!(Ok(Currency::UsDollar), Currency::try_from(840_i32));
+//! assert!(!Currency::is_valid(0));
+//! assert_eq!(Some(Currency::Euro), Currency::from_str_name("EUR"));
+//! ```
+
+use crate::{Amount, AmountError, Currency, Error};
+
+/// The number of nanos in a unit.
+const NANOS_PER_UNIT: i128 = 1_000_000_000;
+
+/// The number of fraction digits of nanos.
+const NANOS_SCALE: u8 = 9;
+
+/// An amount in a currency, in the shape of `google.type.Money`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Money {
+    /// The currency of the amount.
+    currency: Currency,
+    /// The whole units of the amount.
+    units: i64,
+    /// The nano units of the amount, with the same sign as the units.
+    nanos: i32,
+}
+
+impl Money {
+    /// Create money for an amount in the given currency.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::TooManyFractionDigits`] when the amount has more fraction digits than
+    ///   the minor unit of the currency, or than nanos.
+    /// - [`AmountError::Overflow`] when the whole units do not fit in an `i64`.
+    pub const fn new(currency: Currency, amount: Amount) -> Result<Self, AmountError> {
+        if !amount.fits_minor_unit(currency) {
+            return Err(AmountError::TooManyFractionDigits);
+        }
+
+        let value = match amount.rescale(NANOS_SCALE) {
+            Some(amount) => amount.value(),
+            None if amount.fraction_digits() > NANOS_SCALE => {
+                return Err(AmountError::TooManyFractionDigits);
+            }
+            None => return Err(AmountError::Overflow),
+        };
+
+        // Both truncate towards zero, so the units and nanos always have the same sign.
+        let units = value / NANOS_PER_UNIT;
+        #[allow(clippy::cast_possible_truncation)]
+        let nanos = (value % NANOS_PER_UNIT) as i32;
+
+        if units < i64::MIN as i128 || units > i64::MAX as i128 {
+            return Err(AmountError::Overflow);
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let units = units as i64;
+
+        Ok(Self {
+            currency,
+            units,
+            nanos,
+        })
+    }
+
+    /// Create money from the fields of a `google.type.Money` message.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::InvalidCurrency`] when the code is not a currency.
+    /// - [`AmountError::OutOfRange`] when the nanos are not within ±999,999,999, or have a
+    ///   different sign than nonzero units.
+    /// - [`AmountError::TooManyFractionDigits`] when the nanos are more precise than the minor
+    ///   unit of the currency.
+    pub const fn from_parts(
+        currency_code: &str,
+        units: i64,
+        nanos: i32,
+    ) -> Result<Self, AmountError> {
+        let currency = match Currency::from_str_slice(currency_code) {
+            Ok(currency) => currency,
+            Err(error) => return Err(AmountError::InvalidCurrency(error)),
+        };
+
+        if nanos as i128 >= NANOS_PER_UNIT
+            || nanos as i128 <= -NANOS_PER_UNIT
+            || (units > 0 && nanos < 0)
+            || (units < 0 && nanos > 0)
+        {
+            return Err(AmountError::OutOfRange);
+        }
+
+        let retval = Self {
+            currency,
+            units,
+            nanos,
+        };
+
+        if retval.nanos_amount().fits_minor_unit(currency) {
+            Ok(retval)
+        } else {
+            Err(AmountError::TooManyFractionDigits)
+        }
+    }
+
+    /// The fields of a `google.type.Money` message: the currency code, units and nanos.
+    #[must_use]
+    pub const fn into_parts(self) -> (&'static str, i64, i32) {
+        (self.currency.as_str(), self.units, self.nanos)
+    }
+
+    /// The currency of the amount.
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// The whole units of the amount.
+    #[must_use]
+    pub const fn units(&self) -> i64 {
+        self.units
+    }
+
+    /// The nano units of the amount, with the same sign as the units.
+    #[must_use]
+    pub const fn nanos(&self) -> i32 {
+        self.nanos
+    }
+
+    /// The amount, in nano units.
+    const fn nanos_amount(&self) -> Amount {
+        Amount::new(
+            self.units as i128 * NANOS_PER_UNIT + self.nanos as i128,
+            NANOS_SCALE,
+        )
+    }
+
+    /// The amount, with the minor unit of the currency as its scale, if it has one.
+    #[must_use]
+    pub const fn amount(&self) -> Amount {
+        let amount = self.nanos_amount();
+
+        match self.currency.minor_unit() {
+            Some(minor_unit) => match amount.rescale(minor_unit) {
+                Some(amount) => amount,
+                None => amount,
+            },
+            None => amount.normalize(),
+        }
+    }
+}
+
+impl TryFrom<(Currency, Amount)> for Money {
+    type Error = AmountError;
+
+    fn try_from((currency, amount): (Currency, Amount)) -> Result<Self, Self::Error> {
+        Self::new(currency, amount)
+    }
+}
+
+impl From<Money> for (Currency, Amount) {
+    fn from(value: Money) -> Self {
+        (value.currency, value.amount())
+    }
+}
+
+impl Currency {
+    /// Whether the value is the numeric code of a currency.
+    #[must_use]
+    pub const fn is_valid(value: i32) -> bool {
+        Self::from_i32(value).is_ok()
+    }
+
+    /// Try to derive a currency from its numeric code as an `i32`.
+    const fn from_i32(value: i32) -> Result<Self, Error> {
+        if value < 0 || value > u16::MAX as i32 {
+            return Err(Error::InvalidCode);
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self::from_u16(value as u16)
+    }
+
+    /// The name of this currency's enumeration value, which is its code.
+    #[must_use]
+    pub const fn as_str_name(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// The currency with the given enumeration value name, which is its code.
+    #[must_use]
+    pub const fn from_str_name(value: &str) -> Option<Self> {
+        match Self::from_str_slice(value) {
+            Ok(currency) => Some(currency),
+            Err(_) => None,
+        }
+    }
+}
+
+impl From<Currency> for i32 {
+    fn from(value: Currency) -> Self {
+        i32::from(value as u16)
+    }
+}
+
+impl TryFrom<i32> for Currency {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::from_i32(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        type Expected = Result<(i64, i32), AmountError>;
+
+        const CASES: &[(&str, &str, Expected)] = &[
+            ("USD", "1.75", Ok((1, 750_000_000))),
+            ("USD", "-1.75", Ok((-1, -750_000_000))),
+            ("USD", "-0.75", Ok((0, -750_000_000))),
+            ("USD", "0", Ok((0, 0))),
+            ("USD", "1.755", Err(AmountError::TooManyFractionDigits)),
+            ("USD", "1.750", Ok((1, 750_000_000))),
+            ("JPY", "100", Ok((100, 0))),
+            ("JPY", "100.5", Err(AmountError::TooManyFractionDigits)),
+            ("BHD", "-0.001", Ok((0, -1_000_000))),
+            ("XAU", "0.000000001", Ok((0, 1))),
+            (
+                "XAU",
+                "0.0000000001",
+                Err(AmountError::TooManyFractionDigits),
+            ),
+            ("USD", "9223372036854775807", Ok((i64::MAX, 0))),
+            ("USD", "9223372036854775808", Err(AmountError::Overflow)),
+            (
+                "USD",
+                "-9223372036854775808.99",
+                Ok((i64::MIN, -990_000_000)),
+            ),
+        ];
+
+        for &(code, amount, expected) in CASES {
+            let currency = Currency::from_str_slice(code).expect("currency");
+            let amount = Amount::from_str_slice(amount).expect("amount");
+            let actual = Money::new(currency, amount);
+
+            assert_eq!(
+                expected,
+                actual.map(|money| (money.units(), money.nanos())),
+                "{code} {amount}"
+            );
+
+            if let Ok(money) = actual {
+                assert_eq!(amount, money.amount(), "{code} {amount}");
+                assert_eq!(
+                    Ok(money),
+                    Money::from_parts(code, money.units(), money.nanos())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_parts() {
+        const CASES: &[(&str, i64, i32, Result<(), AmountError>)] = &[
+            ("USD", 1, 0, Ok(())),
+            ("USD", 0, -10_000_000, Ok(())),
+            ("USD", 1, -10_000_000, Err(AmountError::OutOfRange)),
+            ("USD", -1, 10_000_000, Err(AmountError::OutOfRange)),
+            ("USD", 0, 1_000_000_000, Err(AmountError::OutOfRange)),
+            ("USD", 0, -1_000_000_000, Err(AmountError::OutOfRange)),
+            ("USD", 0, 1, Err(AmountError::TooManyFractionDigits)),
+            (
+                "JPY",
+                1,
+                500_000_000,
+                Err(AmountError::TooManyFractionDigits),
+            ),
+            (
+                "usd",
+                1,
+                0,
+                Err(AmountError::InvalidCurrency(Error::InvalidCode)),
+            ),
+        ];
+
+        for &(code, units, nanos, expected) in CASES {
+            assert_eq!(
+                expected,
+                Money::from_parts(code, units, nanos).map(|_money| ()),
+                "{code} {units} {nanos}"
+            );
+        }
+
+        assert_eq!(
+            Amount::new(-1, 2),
+            Money::from_parts("USD", 0, -10_000_000)
+                .expect("money")
+                .amount()
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        for currency in Currency::ALL {
+            let value = i32::from(currency);
+
+            assert!(Currency::is_valid(value));
+            assert_eq!(Ok(currency), Currency::try_from(value));
+            assert_eq!(
+                Some(currency),
+                Currency::from_str_name(currency.as_str_name())
+            );
+        }
+
+        for value in [0, -840, 65_536 + 840, i32::MAX, i32::MIN] {
+            assert!(!Currency::is_valid(value));
+            assert_eq!(Err(Error::InvalidCode), Currency::try_from(value));
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! Currency name lookup and search
+
+use crate::Currency;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+    str::Chars,
+};
+
+/// Errors encountered when looking up a currency by name.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum NameError {
+    /// No currency has the name given.
+    Unknown,
+    /// Several currencies have the name given, the first two of which are included, in numeric
+    /// code order.
+    Ambiguous(Currency, Currency),
+}
+
+impl Display for NameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Unknown => f.write_str("no currency has the name"),
+            Self::Ambiguous(first, second) => write!(
+                f,
+                "the name is shared by {} and {}",
+                first.as_str(),
+                second.as_str()
+            ),
+        }
+    }
+}
+
+impl core::error::Error for NameError {}
+
+/// How closely a currency matched a search query, from best to worst.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Rank {
+    /// The query is the whole name, or the code.
+    Exact,
+    /// The name starts with the query.
+    Prefix,
+    /// A word in the name starts with the query.
+    WordPrefix,
+    /// The query appears somewhere in the name.
+    Substring,
+}
+
+impl Rank {
+    /// Every rank, from best to worst.
+    const ALL: [Self; 4] = [Self::Exact, Self::Prefix, Self::WordPrefix, Self::Substring];
+}
+
+/// Fold a character to the lowercase ASCII letters or digit it is written with, if there are
+/// any, e.g. `ß` to `ss`.
+fn fold(c: char) -> Option<(char, Option<char>)> {
+    if c.is_ascii_alphanumeric() {
+        return Some((c.to_ascii_lowercase(), None));
+    }
+
+    let folded = match c {
+        'À'..='Å' | 'à'..='å' | 'Ā'..='ą' => 'a',
+        'Æ' | 'æ' => return Some(('a', Some('e'))),
+        'Ç' | 'ç' | 'Ć'..='č' => 'c',
+        'Ð' | 'ð' | 'Ď'..='đ' => 'd',
+        'È'..='Ë' | 'è'..='ë' | 'Ē'..='ě' => 'e',
+        'Ĝ'..='ģ' => 'g',
+        'Ĥ'..='ħ' => 'h',
+        'Ì'..='Ï' | 'ì'..='ï' | 'Ĩ'..='ı' => 'i',
+        'Ĳ' | 'ĳ' => return Some(('i', Some('j'))),
+        'Ĵ' | 'ĵ' => 'j',
+        'Ķ'..='ĸ' => 'k',
+        'Ĺ'..='ł' => 'l',
+        'Ñ' | 'ñ' | 'Ń'..='ŋ' => 'n',
+        'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' | 'Ō'..='ő' => 'o',
+        'Œ' | 'œ' => return Some(('o', Some('e'))),
+        'Ŕ'..='ř' => 'r',
+        'Ś'..='š' => 's',
+        'ß' => return Some(('s', Some('s'))),
+        'Ţ'..='ŧ' => 't',
+        'Þ' | 'þ' => return Some(('t', Some('h'))),
+        'Ù'..='Ü' | 'ù'..='ü' | 'Ũ'..='ų' => 'u',
+        'Ŵ' | 'ŵ' => 'w',
+        'Ý' | 'ý' | 'ÿ' | 'Ŷ'..='Ÿ' => 'y',
+        'Ź'..='ž' => 'z',
+        _ => return None,
+    };
+
+    Some((folded, None))
+}
+
+/// Whether the character is dropped rather than separating words: an apostrophe, or a combining
+/// diacritical mark, so decomposed letters fold like precomposed ones.
+fn is_dropped(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '`' | '\u{2018}' | '\u{2019}' | '\u{0300}'..='\u{036f}'
+    )
+}
+
+/// The characters of a name, case- and diacritic-folded, with apostrophes and combining marks
+/// dropped and any other run of non-alphanumeric characters collapsed into a single space.
+#[derive(Clone, Debug)]
+struct Normalized<'a> {
+    /// The remaining characters.
+    chars: Chars<'a>,
+    /// The letters or digit to return next, after a separator or the first of a folded pair.
+    pending: [Option<char>; 2],
+    /// Whether a letter or digit has been returned yet.
+    started: bool,
+    /// Whether a separator has been skipped since the last letter or digit.
+    separated: bool,
+}
+
+impl<'a> Normalized<'a> {
+    /// Normalize the given name.
+    fn new(value: &'a str) -> Self {
+        Self {
+            chars: value.chars(),
+            pending: [None; 2],
+            started: false,
+            separated: false,
+        }
+    }
+
+    /// Whether this starts with all of the other.
+    fn starts_with(&self, other: &Self) -> bool {
+        let mut this = self.clone();
+        other.clone().all(|c| this.next() == Some(c))
+    }
+
+    /// The best rank of the query within this name, if it appears at all.
+    fn rank(&self, query: &Self) -> Option<Rank> {
+        if self.clone().eq(query.clone()) {
+            return Some(Rank::Exact);
+        }
+
+        if self.starts_with(query) {
+            return Some(Rank::Prefix);
+        }
+
+        let mut rest = self.clone();
+        let mut retval = None;
+
+        while let Some(c) = rest.next() {
+            if rest.starts_with(query) {
+                if c == ' ' {
+                    return Some(Rank::WordPrefix);
+                }
+
+                retval = Some(Rank::Substring);
+            }
+        }
+
+        retval
+    }
+}
+
+impl Iterator for Normalized<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending[0].take() {
+            self.pending = [self.pending[1].take(), None];
+            return Some(c);
+        }
+
+        for c in self.chars.by_ref() {
+            if let Some((first, second)) = fold(c) {
+                let separated = self.started && self.separated;
+                self.started = true;
+                self.separated = false;
+
+                if separated {
+                    self.pending = [Some(first), second];
+                    return Some(' ');
+                }
+
+                self.pending = [second, None];
+                return Some(first);
+            }
+
+            if !is_dropped(c) {
+                self.separated = true;
+            }
+        }
+
+        None
+    }
+}
+
+impl Currency {
+    /// The currency with the given English name, ignoring case, diacritics and punctuation.
+    ///
+    /// A name may be shared by several currencies, as `Bolívar Soberano` is by `VED` and `VES`,
+    /// in which case the candidates are returned in an error, so the caller can choose:
+    ///
+    /// ```rust
+    /// use iso4217_static::{Currency, search::NameError};
+    ///
+    /// assert_eq!(Ok(Currency::UsDollar), Currency::from_name("us dollar"));
+    /// assert_eq!(Ok(Currency::Zloty), Currency::from_name("ZLOTY"));
+    /// assert_eq!(
+    ///     Err(NameError::Ambiguous(
+    ///         Currency::BolivarDigital,
+    ///         Currency::BolivarSoberano
+    ///     )),
+    ///     Currency::from_name("Bolivar Soberano")
+    /// );
+    /// assert_eq!(Err(NameError::Unknown), Currency::from_name("dollar"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`NameError::Unknown`] when no currency has the name.
+    /// - [`NameError::Ambiguous`] when several currencies have the name.
+    pub fn from_name(name: &str) -> Result<Self, NameError> {
+        let name = Normalized::new(name);
+        let mut matches = Self::ALL
+            .into_iter()
+            .filter(|currency| Normalized::new(currency.name()).eq(name.clone()));
+
+        match (matches.next(), matches.next()) {
+            (Some(currency), None) => Ok(currency),
+            (Some(first), Some(second)) => Err(NameError::Ambiguous(first, second)),
+            (None, _) => Err(NameError::Unknown),
+        }
+    }
+
+    /// Search for currencies whose name or code matches a partial query.
+    ///
+    /// Matching ignores case, diacritics and punctuation, as with [`Currency::from_name()`].
+    /// Candidates are returned best [`Rank`] first, then in numeric code order:
+    ///
+    /// ```rust
+    /// use iso4217_static::{Currency, search::Rank};
+    ///
+    /// let mut results = Currency::search("franc");
+    /// assert!(results.any(|result| result == (Currency::SwissFranc, Rank::WordPrefix)));
+    /// assert_eq!(Some((Currency::Euro, Rank::Exact)), Currency::search("eur").next());
+    /// ```
+    #[must_use]
+    pub fn search(query: &str) -> Search<'_> {
+        Search {
+            code: query.trim(),
+            query: Normalized::new(query),
+            rank: 0,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the currencies matching a query, best match first.
+///
+/// This is created by [`Currency::search()`].
+#[derive(Clone, Debug)]
+pub struct Search<'a> {
+    /// The trimmed query, to compare with codes.
+    code: &'a str,
+    /// The normalized query, to compare with names.
+    query: Normalized<'a>,
+    /// The position of the rank currently being returned in [`Rank::ALL`].
+    rank: usize,
+    /// The position of the next currency to check in [`Currency::ALL`].
+    index: usize,
+}
+
+impl Search<'_> {
+    /// The rank of the given currency for this query, if it matches at all.
+    fn rank_of(&self, currency: Currency) -> Option<Rank> {
+        if currency.as_str().eq_ignore_ascii_case(self.code) {
+            return Some(Rank::Exact);
+        }
+
+        Normalized::new(currency.name()).rank(&self.query)
+    }
+}
+
+impl Iterator for Search<'_> {
+    type Item = (Currency, Rank);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // An empty query would match everything.
+        self.query.clone().next()?;
+
+        while let Some(&rank) = Rank::ALL.get(self.rank) {
+            while let Some(currency) = Currency::from_index(self.index) {
+                self.index += 1;
+
+                if self.rank_of(currency) == Some(rank) {
+                    return Some((currency, rank));
+                }
+            }
+
+            self.rank += 1;
+            self.index = 0;
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for Search<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalized() {
+        const CASES: &[(&str, &str)] = &[
+            ("US Dollar", "us dollar"),
+            ("  US   Dollar (Next day) ", "us dollar next day"),
+            ("Bolívar Soberano", "bolivar soberano"),
+            ("Boli\u{301}var Soberano", "bolivar soberano"),
+            ("Złoty", "zloty"),
+            ("Ħ Straße", "h strasse"),
+            ("Æ-Þ", "ae th"),
+            ("Pa’anga", "paanga"),
+            ("Pa'anga", "paanga"),
+            ("CFA Franc BCEAO", "cfa franc bceao"),
+        ];
+
+        for &(value, expected) in CASES {
+            assert!(Normalized::new(value).eq(expected.chars()), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn from_name() {
+        const BOLIVAR: Result<Currency, NameError> = Err(NameError::Ambiguous(
+            Currency::BolivarDigital,
+            Currency::BolivarSoberano,
+        ));
+
+        for currency in Currency::ALL {
+            match Currency::from_name(currency.name()) {
+                Ok(found) => assert_eq!(currency, found),
+                Err(NameError::Ambiguous(first, second)) => {
+                    assert_eq!(currency.name(), first.name());
+                    assert_eq!(currency.name(), second.name());
+                }
+                Err(error) => panic!("{}: {error}", currency.name()),
+            }
+        }
+
+        assert_eq!(BOLIVAR, Currency::from_name("Bolivar Soberano"));
+        assert_eq!(BOLIVAR, Currency::from_name("BOLIVAR SOBERANO"));
+        assert_eq!(BOLIVAR, Currency::from_name("Boli\u{301}var Soberano"));
+        assert_eq!(Ok(Currency::Zloty), Currency::from_name("Złoty"));
+        assert_eq!(
+            Currency::from_str_slice("TOP").map_err(|_error| NameError::Unknown),
+            Currency::from_name("pa'anga")
+        );
+        assert_eq!(Err(NameError::Unknown), Currency::from_name(""));
+    }
+
+    #[test]
+    fn search() {
+        assert!(
+            Currency::search("dollar").all(|(currency, _rank)| currency.name().contains("Dollar"))
+        );
+        assert!(
+            Currency::search("dollar")
+                .any(|result| result == (Currency::UsDollar, Rank::WordPrefix))
+        );
+        assert!(
+            Currency::search("dollar")
+                .map(|(_currency, rank)| rank)
+                .is_sorted()
+        );
+
+        assert_eq!(
+            Some((Currency::UsDollar, Rank::Exact)),
+            Currency::search(" usd ").next()
+        );
+        assert_eq!(
+            Some((Currency::UsDollar, Rank::Prefix)),
+            Currency::search("us dol").next()
+        );
+        assert!(Currency::search("ollar").all(|(_currency, rank)| rank == Rank::Substring));
+        assert_eq!(None, Currency::search(" ").next());
+    }
+}
@@ -105,6 +105,69 @@ pub mod u16 {
     }
 }
 
+/// Serialize/Deserialize an ISO 4217 currency code as an XBRL unit `QName`, e.g. `iso4217:USD`.
+///
+/// # Examples
+///
+/// ```
+/// use iso4217_static::Currency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "iso4217_static::serde::qname")]
+///     measure: Currency,
+/// }
+///
+/// const EXPECTED: &str = r#"{"measure":"iso4217:USD"}"#;
+///
+/// let input = MyStruct { measure: Currency::UsDollar };
+/// let actual = serde_json::to_string(&input).expect("ser");
+///
+/// assert_eq!(EXPECTED, &actual);
+///
+/// let output = serde_json::from_str(&actual).expect("de");
+///
+/// assert_eq!(input, output);
+/// ```
+pub mod qname {
+    use crate::{Currency, serde::QNameVisitor};
+    use serde::{Deserializer, Serializer};
+
+    /// Deserialize a given `QName` into a `Currency`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if there is a problem deserializing the value.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Currency, D::Error> {
+        deserializer.deserialize_str(QNameVisitor)
+    }
+
+    /// Serialize a `Currency` into a `QName` string.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if there is a problem serializing the value.
+    pub fn serialize<S: Serializer>(value: &Currency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.qname())
+    }
+}
+
+/// A visitor for deserializing an XBRL unit `QName`.
+struct QNameVisitor;
+
+impl Visitor<'_> for QNameVisitor {
+    type Value = Currency;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an ISO 4217 unit QName")
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        Currency::from_qname(v).map_err(|_error| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
 /// A visitor for deserializing a currency value.
 struct CurrencyVisitor;
 
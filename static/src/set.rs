@@ -0,0 +1,428 @@
+//! Fixed-size currency sets
+
+use crate::{Currency, Error};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign},
+};
+
+/// The number of words needed for one bit per currency.
+const WORDS: usize = Currency::COUNT.div_ceil(u64::BITS as usize);
+
+/// A word-wise set operation.
+#[derive(Clone, Copy)]
+enum Op {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// A set of currencies, stored as a fixed-size bitset indexed by [`Currency::index()`].
+///
+/// This neither allocates nor hashes, and most operations are `const`, so sets can be built at
+/// compile time:
+///
+/// ```rust
+/// use iso4217_static::{Currency, CurrencySet};
+///
+/// const SETTLEABLE: CurrencySet = match CurrencySet::from_codes(&["EUR", "GBP", "USD"]) {
+///     Ok(set) => set,
+///     Err(_) => panic!("invalid currency code"),
+/// };
+///
+/// assert!(SETTLEABLE.contains(Currency::Euro));
+/// assert!(!SETTLEABLE.contains(Currency::Yen));
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CurrencySet {
+    /// One bit per currency, by index.
+    bits: [u64; WORDS],
+}
+
+impl CurrencySet {
+    /// A set with no currencies.
+    pub const EMPTY: Self = Self { bits: [0; WORDS] };
+
+    /// A set with every currency.
+    pub const FULL: Self = Self::from_slice(&Currency::ALL);
+
+    /// Create a new, empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Create a set containing the given currencies.
+    #[must_use]
+    pub const fn from_slice(currencies: &[Currency]) -> Self {
+        let mut retval = Self::EMPTY;
+        let mut i = 0;
+
+        while i < currencies.len() {
+            retval.insert(currencies[i]);
+            i += 1;
+        }
+
+        retval
+    }
+
+    /// Create a set containing the currencies with the given 3-character codes.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error`] when one of the codes is not a valid currency code, see
+    ///   [`Currency::from_str_slice()`].
+    pub const fn from_codes(codes: &[&str]) -> Result<Self, Error> {
+        let mut retval = Self::EMPTY;
+        let mut i = 0;
+
+        while i < codes.len() {
+            match Currency::from_str_slice(codes[i]) {
+                Ok(currency) => {
+                    retval.insert(currency);
+                }
+                Err(error) => return Err(error),
+            }
+            i += 1;
+        }
+
+        Ok(retval)
+    }
+
+    /// The word and mask for the given currency.
+    const fn position(currency: Currency) -> (usize, u64) {
+        let index = currency.index();
+        (index / 64, 1 << (index % 64))
+    }
+
+    /// Whether the set contains the given currency.
+    #[must_use]
+    pub const fn contains(&self, currency: Currency) -> bool {
+        let (word, mask) = Self::position(currency);
+        self.bits[word] & mask != 0
+    }
+
+    /// Add a currency to the set, returning whether it was newly added.
+    pub const fn insert(&mut self, currency: Currency) -> bool {
+        let (word, mask) = Self::position(currency);
+        let retval = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        retval
+    }
+
+    /// Remove a currency from the set, returning whether it was present.
+    pub const fn remove(&mut self, currency: Currency) -> bool {
+        let (word, mask) = Self::position(currency);
+        let retval = self.bits[word] & mask != 0;
+        self.bits[word] &= !mask;
+        retval
+    }
+
+    /// Remove every currency from the set.
+    pub const fn clear(&mut self) {
+        self.bits = [0; WORDS];
+    }
+
+    /// The number of currencies in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        let mut retval = 0;
+        let mut word = 0;
+
+        while word < WORDS {
+            retval += self.bits[word].count_ones() as usize;
+            word += 1;
+        }
+
+        retval
+    }
+
+    /// Whether the set has no currencies.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        let mut word = 0;
+
+        while word < WORDS {
+            if self.bits[word] != 0 {
+                return false;
+            }
+            word += 1;
+        }
+
+        true
+    }
+
+    /// Combine the words of two sets with the given operation.
+    const fn combine(self, other: Self, op: Op) -> Self {
+        let mut retval = Self::EMPTY;
+        let mut word = 0;
+
+        while word < WORDS {
+            let (lhs, rhs) = (self.bits[word], other.bits[word]);
+            retval.bits[word] = match op {
+                Op::Union => lhs | rhs,
+                Op::Intersection => lhs & rhs,
+                Op::Difference => lhs & !rhs,
+                Op::SymmetricDifference => lhs ^ rhs,
+            };
+            word += 1;
+        }
+
+        retval
+    }
+
+    /// The currencies in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        self.combine(other, Op::Union)
+    }
+
+    /// The currencies in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        self.combine(other, Op::Intersection)
+    }
+
+    /// The currencies in this set, but not the other.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        self.combine(other, Op::Difference)
+    }
+
+    /// The currencies in exactly one of the sets.
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        self.combine(other, Op::SymmetricDifference)
+    }
+
+    /// Whether every currency in this set is also in the other.
+    #[must_use]
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.difference(*other).is_empty()
+    }
+
+    /// Whether the sets have no currencies in common.
+    #[must_use]
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(*other).is_empty()
+    }
+
+    /// Iterate over the currencies in the set, in numeric code order.
+    #[must_use]
+    pub const fn iter(&self) -> Iter {
+        Iter {
+            bits: self.bits,
+            word: 0,
+        }
+    }
+}
+
+impl Debug for CurrencySet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Currency> for CurrencySet {
+    fn from_iter<T: IntoIterator<Item = Currency>>(iter: T) -> Self {
+        let mut retval = Self::EMPTY;
+        retval.extend(iter);
+        retval
+    }
+}
+
+impl Extend<Currency> for CurrencySet {
+    fn extend<T: IntoIterator<Item = Currency>>(&mut self, iter: T) {
+        for currency in iter {
+            self.insert(currency);
+        }
+    }
+}
+
+impl IntoIterator for CurrencySet {
+    type Item = Currency;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CurrencySet {
+    type Item = Currency;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl BitOr for CurrencySet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for CurrencySet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for CurrencySet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for CurrencySet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitXor for CurrencySet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for CurrencySet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for CurrencySet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for CurrencySet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+/// An iterator over the currencies in a [`CurrencySet`], in numeric code order.
+#[derive(Clone, Debug)]
+pub struct Iter {
+    /// The bits which have not been visited yet.
+    bits: [u64; WORDS],
+
+    /// The word currently being visited.
+    word: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Currency;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word < WORDS {
+            let bits = self.bits[self.word];
+
+            if bits != 0 {
+                self.bits[self.word] = bits & (bits - 1);
+                return Currency::from_index(self.word * 64 + bits.trailing_zeros() as usize);
+            }
+
+            self.word += 1;
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = CurrencySet { bits: self.bits }.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl FusedIterator for Iter {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        let lhs = CurrencySet::from_slice(&[Currency::Euro, Currency::UsDollar, Currency::Yen]);
+        let rhs = CurrencySet::from_codes(&["USD", "GBP"]).expect("codes");
+
+        assert_eq!(3, lhs.len());
+        assert!(lhs.contains(Currency::Yen));
+        assert!(!lhs.contains(Currency::PoundSterling));
+
+        assert_eq!(
+            CurrencySet::from_slice(&[
+                Currency::Euro,
+                Currency::PoundSterling,
+                Currency::UsDollar,
+                Currency::Yen
+            ]),
+            lhs | rhs
+        );
+        assert_eq!(CurrencySet::from_slice(&[Currency::UsDollar]), lhs & rhs);
+        assert_eq!(
+            CurrencySet::from_slice(&[Currency::Euro, Currency::Yen]),
+            lhs - rhs
+        );
+        assert_eq!(
+            CurrencySet::from_slice(&[Currency::Euro, Currency::PoundSterling, Currency::Yen]),
+            lhs ^ rhs
+        );
+        assert!((lhs & rhs).is_subset(&rhs));
+        assert!((lhs - rhs).is_disjoint(&rhs));
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = CurrencySet::new();
+
+        assert!(set.is_empty());
+        assert!(set.insert(Currency::UsDollar));
+        assert!(!set.insert(Currency::UsDollar));
+        assert!(set.remove(Currency::UsDollar));
+        assert!(!set.remove(Currency::UsDollar));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        let set = CurrencySet::FULL;
+
+        assert_eq!(Currency::COUNT, set.len());
+        assert_eq!(Currency::COUNT, set.iter().len());
+        assert!(set.iter().eq(Currency::ALL));
+
+        let set = [Currency::Yen, Currency::Lek, Currency::UsDollar]
+            .into_iter()
+            .collect::<CurrencySet>();
+
+        assert!(
+            set.iter()
+                .eq([Currency::Lek, Currency::Yen, Currency::UsDollar])
+        );
+    }
+
+    #[test]
+    fn from_codes_error() {
+        assert_eq!(
+            Err(Error::InvalidCode),
+            CurrencySet::from_codes(&["USD", "ZZZ"])
+        );
+    }
+}
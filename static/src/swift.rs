@@ -0,0 +1,368 @@
+//! SWIFT MT currency and amount fields
+//!
+//! SWIFT MT messages carry amounts as a currency code followed by up to 15 characters of
+//! digits, with a mandatory `,` decimal separator and no more fraction digits than the minor
+//! unit of the currency. Fields `33B` (instructed amount) and `71F` (sender's charges) are a
+//! [`CurrencyAmount`], and field `32A` prefixes one with a `YYMMDD` value date as a
+//! [`ValueDateCurrencyAmount`]:
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use iso4217_static::{Amount, Currency, swift::ValueDateCurrencyAmount};
+//!
+//! let field = ValueDateCurrencyAmount::from_str_slice("230915EUR1234,5").expect("32A");
+//! assert_eq!(NaiveDate::from_ymd_opt(2023, 9, 15), Some(field.date()));
+//! assert_eq!(Currency::Euro, field.currency());
+//! assert_eq!(Amount::new(12_345, 1), field.amount());
+//! assert_eq!("230915EUR1234,5", field.to_string());
+//!
+//! assert!(ValueDateCurrencyAmount::from_str_slice("230915JPY1234,5").is_err());
+//! ```
+
+use crate::{Amount, AmountError, Currency};
+use chrono::{Datelike, NaiveDate};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    str::FromStr,
+};
+
+/// The most characters an amount may have, including the decimal separator.
+pub const MAX_AMOUNT_LEN: usize = 15;
+
+/// The number of characters the amount takes when written.
+const fn amount_len(amount: Amount) -> usize {
+    let mut value = amount.value().unsigned_abs();
+    let mut digits = 1;
+
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+
+    let scale = amount.scale() as usize;
+    let int = if digits > scale { digits - scale } else { 1 };
+
+    int + 1 + scale
+}
+
+/// Check an amount in the given currency can be written in a field.
+const fn validate(currency: Currency, amount: Amount) -> Result<(), AmountError> {
+    if amount.is_negative() || amount_len(amount) > MAX_AMOUNT_LEN {
+        return Err(AmountError::OutOfRange);
+    }
+
+    // Unlike most formats, the fraction digits written count, rather than the significant ones.
+    match currency.minor_unit() {
+        Some(minor_unit) if amount.scale() > minor_unit => Err(AmountError::TooManyFractionDigits),
+        _ => Ok(()),
+    }
+}
+
+/// Parse an amount with a `,` decimal separator and at least one integer digit.
+const fn parse_amount(bytes: &[u8]) -> Result<Amount, AmountError> {
+    if bytes.len() > MAX_AMOUNT_LEN {
+        return Err(AmountError::OutOfRange);
+    }
+
+    let mut has_separator = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b',' {
+            has_separator = true;
+        }
+
+        i += 1;
+    }
+
+    match bytes {
+        [b'0'..=b'9', ..] if has_separator => Amount::parse(bytes, b','),
+        _ => Err(AmountError::InvalidFormat),
+    }
+}
+
+/// Write an amount with a `,` decimal separator, which is written even without fraction digits.
+fn write_amount(f: &mut impl Write, amount: Amount) -> FmtResult {
+    amount.write(f, ',')?;
+
+    if amount.scale() == 0 {
+        f.write_char(',')?;
+    }
+
+    Ok(())
+}
+
+/// A currency code followed by an amount, as in fields `33B` and `71F`, e.g. `USD1000,`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CurrencyAmount {
+    /// The currency of the amount.
+    currency: Currency,
+    /// The amount.
+    amount: Amount,
+}
+
+impl CurrencyAmount {
+    /// Create a new amount in the given currency.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::OutOfRange`] when the amount is negative or too long to write.
+    /// - [`AmountError::TooManyFractionDigits`] when the amount has more fraction digits than the
+    ///   minor unit of the currency.
+    pub const fn new(currency: Currency, amount: Amount) -> Result<Self, AmountError> {
+        match validate(currency, amount) {
+            Ok(()) => Ok(Self { currency, amount }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Parse the content of a field, e.g. `EUR1234,56`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::InvalidCurrency`] when the field does not start with a currency code.
+    /// - [`AmountError::InvalidFormat`] when the amount is not digits with a `,` separator.
+    /// - [`AmountError`] when the amount is not valid, see [`CurrencyAmount::new()`].
+    pub const fn from_str_slice(value: &str) -> Result<Self, AmountError> {
+        let (currency, amount) = match value.as_bytes() {
+            [a, b, c, rest @ ..] => (Currency::from_bytes([*a, *b, *c]), rest),
+            _ => return Err(AmountError::InvalidCurrency(crate::Error::InvalidLength)),
+        };
+
+        let currency = match currency {
+            Ok(currency) => currency,
+            Err(error) => return Err(AmountError::InvalidCurrency(error)),
+        };
+
+        match parse_amount(amount) {
+            Ok(amount) => Self::new(currency, amount),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// The currency of the amount.
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// The amount.
+    #[must_use]
+    pub const fn amount(&self) -> Amount {
+        self.amount
+    }
+}
+
+impl Display for CurrencyAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.currency.as_str())?;
+        write_amount(f, self.amount)
+    }
+}
+
+impl FromStr for CurrencyAmount {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_slice(s)
+    }
+}
+
+impl TryFrom<(Currency, Amount)> for CurrencyAmount {
+    type Error = AmountError;
+
+    fn try_from((currency, amount): (Currency, Amount)) -> Result<Self, Self::Error> {
+        Self::new(currency, amount)
+    }
+}
+
+/// A `YYMMDD` value date followed by a currency code and an amount, as in field `32A`, e.g.
+/// `230915USD1000,`.
+///
+/// Two-digit years are read as 2000–2099.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ValueDateCurrencyAmount {
+    /// The value date.
+    date: NaiveDate,
+    /// The currency and amount.
+    currency_amount: CurrencyAmount,
+}
+
+impl ValueDateCurrencyAmount {
+    /// Create a new amount in the given currency, for the given value date.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::InvalidDate`] when the date is not in 2000–2099.
+    /// - [`AmountError`] when the amount is not valid, see [`CurrencyAmount::new()`].
+    pub fn new(date: NaiveDate, currency: Currency, amount: Amount) -> Result<Self, AmountError> {
+        if !(2000..=2099).contains(&date.year()) {
+            return Err(AmountError::InvalidDate);
+        }
+
+        Ok(Self {
+            date,
+            currency_amount: CurrencyAmount::new(currency, amount)?,
+        })
+    }
+
+    /// Parse the content of a field, e.g. `230915EUR1234,56`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AmountError::InvalidDate`] when the field does not start with a valid `YYMMDD` date.
+    /// - [`AmountError`] when the currency or amount is not valid, see
+    ///   [`CurrencyAmount::from_str_slice()`].
+    pub fn from_str_slice(value: &str) -> Result<Self, AmountError> {
+        let (date, rest) = value.split_at_checked(6).ok_or(AmountError::InvalidDate)?;
+
+        if !date.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(AmountError::InvalidDate);
+        }
+
+        let number = |range| date.get(range).and_then(|digits: &str| digits.parse().ok());
+        let date = number(0..2)
+            .zip(number(2..4))
+            .zip(number(4..6))
+            .and_then(|((year, month), day)| {
+                NaiveDate::from_ymd_opt(2000 + i32::try_from(year).ok()?, month, day)
+            })
+            .ok_or(AmountError::InvalidDate)?;
+
+        Ok(Self {
+            date,
+            currency_amount: CurrencyAmount::from_str_slice(rest)?,
+        })
+    }
+
+    /// The value date.
+    #[must_use]
+    pub const fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// The currency of the amount.
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency_amount.currency
+    }
+
+    /// The amount.
+    #[must_use]
+    pub const fn amount(&self) -> Amount {
+        self.currency_amount.amount
+    }
+
+    /// The currency and amount, without the value date.
+    #[must_use]
+    pub const fn currency_amount(&self) -> CurrencyAmount {
+        self.currency_amount
+    }
+}
+
+impl Display for ValueDateCurrencyAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:02}{:02}{:02}{}",
+            self.date.year() % 100,
+            self.date.month(),
+            self.date.day(),
+            self.currency_amount
+        )
+    }
+}
+
+impl FromStr for ValueDateCurrencyAmount {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_slice(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn currency_amount() {
+        type Expected = Result<(Currency, Amount), AmountError>;
+
+        const CASES: &[(&str, Expected)] = &[
+            ("EUR1234,56", Ok((Currency::Euro, Amount::new(123_456, 2)))),
+            ("USD1000,", Ok((Currency::UsDollar, Amount::new(1000, 0)))),
+            ("JPY0,", Ok((Currency::Yen, Amount::new(0, 0)))),
+            ("EUR1234,500", Err(AmountError::TooManyFractionDigits)),
+            ("JPY100,5", Err(AmountError::TooManyFractionDigits)),
+            ("EUR1234", Err(AmountError::InvalidFormat)),
+            ("EUR1234.56", Err(AmountError::InvalidFormat)),
+            ("EUR,56", Err(AmountError::InvalidFormat)),
+            ("EUR-1,00", Err(AmountError::InvalidFormat)),
+            ("EUR1,2,3", Err(AmountError::InvalidFormat)),
+            ("EUR1234567890123,45", Err(AmountError::OutOfRange)),
+            (
+                "EUR12345678901,23",
+                Ok((Currency::Euro, Amount::new(1_234_567_890_123, 2))),
+            ),
+            (
+                "eur1,",
+                Err(AmountError::InvalidCurrency(Error::InvalidCode)),
+            ),
+            (
+                "EU",
+                Err(AmountError::InvalidCurrency(Error::InvalidLength)),
+            ),
+        ];
+
+        for &(value, expected) in CASES {
+            let actual = CurrencyAmount::from_str_slice(value);
+            assert_eq!(
+                expected,
+                actual.map(|field| (field.currency(), field.amount())),
+                "{value:?}"
+            );
+        }
+
+        assert_eq!(
+            Err(AmountError::OutOfRange),
+            CurrencyAmount::new(Currency::Euro, Amount::new(-1, 0))
+        );
+    }
+
+    #[test]
+    fn value_date_currency_amount() {
+        type Expected = Result<(i32, u32, u32), AmountError>;
+
+        const CASES: &[(&str, Expected)] = &[
+            ("230915EUR1,", Ok((2023, 9, 15))),
+            ("000101EUR1,", Ok((2000, 1, 1))),
+            ("240229EUR1,", Ok((2024, 2, 29))),
+            ("230229EUR1,", Err(AmountError::InvalidDate)),
+            ("231301EUR1,", Err(AmountError::InvalidDate)),
+            ("2309EUR1,", Err(AmountError::InvalidDate)),
+            ("+23091EUR1,", Err(AmountError::InvalidDate)),
+            ("23091", Err(AmountError::InvalidDate)),
+            ("230915EUR1", Err(AmountError::InvalidFormat)),
+        ];
+
+        for &(value, expected) in CASES {
+            let actual = ValueDateCurrencyAmount::from_str_slice(value);
+            assert_eq!(
+                expected.map(|(year, month, day)| NaiveDate::from_ymd_opt(year, month, day)),
+                actual.map(|field| Some(field.date())),
+                "{value:?}"
+            );
+        }
+
+        assert_eq!(
+            Err(AmountError::InvalidDate),
+            ValueDateCurrencyAmount::new(
+                NaiveDate::from_ymd_opt(1999, 12, 31).unwrap_or_default(),
+                Currency::Euro,
+                Amount::new(1, 0)
+            )
+        );
+    }
+}
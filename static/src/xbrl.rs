@@ -0,0 +1,172 @@
+//! XBRL unit `QName`s
+//!
+//! XBRL instances express monetary units as `QName`s in the ISO 4217 namespace, conventionally
+//! with the `iso4217` prefix, e.g. `<xbrli:measure>iso4217:USD</xbrli:measure>`:
+//!
+//! ```rust
+//! use iso4217_static::Currency;
+//!
+//! assert_eq!(Ok(Currency::UsDollar), Currency::from_qname("iso4217:USD"));
+//! assert_eq!(
+//!     Ok(Currency::UsDollar),
+//!     Currency::from_qname("{http://www.xbrl.org/2003/iso4217}USD")
+//! );
+//! assert_eq!("iso4217:EUR", Currency::Euro.qname().to_string());
+//! ```
+//!
+//! Filings may use any code in the ISO 4217 list this crate was generated from, including funds
+//! and the `X` codes. Historic codes are not part of that list, so they are not accepted.
+
+use crate::{Currency, Error};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// The namespace of ISO 4217 units in XBRL.
+pub const NAMESPACE: &str = "http://www.xbrl.org/2003/iso4217";
+
+/// The conventional prefix of the ISO 4217 namespace in XBRL.
+pub const PREFIX: &str = "iso4217";
+
+/// The rest of the bytes after the given prefix, if they start with it.
+const fn strip_prefix<'a>(bytes: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if bytes.len() < prefix.len() {
+        return None;
+    }
+
+    let (start, rest) = bytes.split_at(prefix.len());
+    let mut i = 0;
+
+    while i < prefix.len() {
+        if start[i] != prefix[i] {
+            return None;
+        }
+
+        i += 1;
+    }
+
+    Some(rest)
+}
+
+/// The local name of an ISO 4217 `QName`, either `iso4217:XXX` or `{namespace}XXX`.
+const fn local_name(bytes: &[u8]) -> Option<&[u8]> {
+    if let Some(rest) = strip_prefix(bytes, PREFIX.as_bytes())
+        && let [b':', rest @ ..] = rest
+    {
+        return Some(rest);
+    }
+
+    if let [b'{', rest @ ..] = bytes
+        && let Some(rest) = strip_prefix(rest, NAMESPACE.as_bytes())
+        && let [b'}', rest @ ..] = rest
+    {
+        return Some(rest);
+    }
+
+    None
+}
+
+/// The `QName` of a currency as an XBRL unit, which displays as e.g. `iso4217:USD`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct QName(pub Currency);
+
+impl Display for QName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(PREFIX)?;
+        f.write_str(":")?;
+        f.write_str(self.0.as_str())
+    }
+}
+
+impl FromStr for QName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::from_qname(s).map(Self)
+    }
+}
+
+impl From<Currency> for QName {
+    fn from(value: Currency) -> Self {
+        Self(value)
+    }
+}
+
+impl From<QName> for Currency {
+    fn from(value: QName) -> Self {
+        value.0
+    }
+}
+
+impl Currency {
+    /// Try to derive a currency from an XBRL unit `QName`, either with the `iso4217` prefix, e.g.
+    /// `iso4217:USD`, or with the namespace in Clark notation, e.g.
+    /// `{http://www.xbrl.org/2003/iso4217}USD`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCode`] when the `QName` is not in the ISO 4217 namespace, or the local
+    ///   name is not a currency code.
+    /// - [`Error::InvalidLength`] when the local name is not three bytes long.
+    /// - [`Error::InvalidCharset`] when the local name is not ASCII.
+    pub const fn from_qname(value: &str) -> Result<Self, Error> {
+        match local_name(value.as_bytes()) {
+            Some(&[a, b, c]) => Self::from_bytes([a, b, c]),
+            Some(_) => Err(Error::InvalidLength),
+            None => Err(Error::InvalidCode),
+        }
+    }
+
+    /// The XBRL unit `QName` of this currency.
+    #[must_use]
+    pub const fn qname(self) -> QName {
+        QName(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::buf::Buf;
+    use core::fmt::Write;
+
+    #[test]
+    fn from_qname() {
+        const CASES: &[(&str, Result<Currency, Error>)] = &[
+            ("iso4217:USD", Ok(Currency::UsDollar)),
+            (
+                "{http://www.xbrl.org/2003/iso4217}USD",
+                Ok(Currency::UsDollar),
+            ),
+            ("iso4217:XTS", Currency::from_str_slice("XTS")),
+            ("USD", Err(Error::InvalidCode)),
+            ("ISO4217:USD", Err(Error::InvalidCode)),
+            ("xbrli:USD", Err(Error::InvalidCode)),
+            (
+                "{http://www.xbrl.org/2003/instance}USD",
+                Err(Error::InvalidCode),
+            ),
+            ("iso4217:usd", Err(Error::InvalidCode)),
+            ("iso4217:ZZZ", Err(Error::InvalidCode)),
+            ("iso4217:USDX", Err(Error::InvalidLength)),
+            ("iso4217:", Err(Error::InvalidLength)),
+        ];
+
+        for &(value, expected) in CASES {
+            assert_eq!(expected, Currency::from_qname(value), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn qname() {
+        for currency in Currency::ALL {
+            let mut buf = Buf::new();
+            write!(buf, "{}", currency.qname()).expect("display");
+
+            let qname = core::str::from_utf8(buf.as_bytes()).expect("ascii");
+            assert_eq!(Ok(currency.qname()), qname.parse());
+            assert_eq!(Some(currency.as_str()), qname.strip_prefix("iso4217:"));
+        }
+    }
+}